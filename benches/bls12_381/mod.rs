@@ -114,6 +114,47 @@ fn bench_pairing_full(b: &mut ::test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_gt_mul_scalar(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SAMPLES: usize = 100;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<(Gt, Scalar)> = (0..SAMPLES)
+        .map(|_| (Gt::random(&mut rng), Scalar::random(&mut rng)))
+        .collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let tmp = v[count].0 * v[count].1;
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
+#[bench]
+fn bench_gt_is_identity(b: &mut ::test::Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Gt> = (0..SAMPLES).map(|_| Gt::random(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let tmp = v[count].is_identity();
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
 #[bench]
 fn bench_g1_multi_exp_naive(b: &mut ::test::Bencher) {
     use ff::Field;
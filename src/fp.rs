@@ -17,7 +17,7 @@ use crate::fp2::Fp2;
 
 // Little-endian non-Montgomery form.
 #[allow(dead_code)]
-const MODULUS: [u64; 6] = [
+pub(crate) const MODULUS: [u64; 6] = [
     0xb9fe_ffff_ffff_aaab,
     0x1eab_fffe_b153_ffff,
     0x6730_d2a0_f6b0_f624,
@@ -616,9 +616,19 @@ impl Fp {
         bytes
     }
 
+    /// Writes the big-endian encodings of `elements` into `out`, one after another.
+    ///
+    /// Panics if `out` is not exactly `48 * elements.len()` bytes long.
+    pub fn batch_to_bytes_be(elements: &[Fp], out: &mut [u8]) {
+        assert_eq!(out.len(), 48 * elements.len());
+        for (element, chunk) in elements.iter().zip(out.chunks_exact_mut(48)) {
+            unsafe { blst_bendian_from_fp(chunk.as_mut_ptr(), &element.0) };
+        }
+    }
+
     /// Constructs an element of `Fp` from a little-endian array of limbs without checking that it
     /// is canonical and without converting it to Montgomery form (i.e. without multiplying by `R`).
-    pub fn from_raw_unchecked(l: [u64; 6]) -> Fp {
+    pub const fn from_raw_unchecked(l: [u64; 6]) -> Fp {
         Fp(blst_fp { l })
     }
 
@@ -304,6 +304,13 @@ impl Fp12 {
     pub fn conjugate(&mut self) {
         unsafe { blst_fp12_conjugate(&mut self.0) };
     }
+
+    /// Squares this element, which must be in the cyclotomic subgroup.
+    pub fn cyclotomic_square(&self) -> Fp12 {
+        let mut out = blst_fp12::default();
+        unsafe { blst_fp12_cyclotomic_sqr(&mut out, &self.0) };
+        Fp12(out)
+    }
 }
 
 #[cfg(feature = "gpu")]
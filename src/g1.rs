@@ -1051,6 +1051,34 @@ impl fmt::Debug for G1Compressed {
     }
 }
 
+/// A `G1Affine` point prepared for [`multi_miller_loop_g1_prepared`](crate::multi_miller_loop_g1_prepared).
+///
+/// This exists for API symmetry with [`G2Prepared`](crate::G2Prepared) and gives no
+/// speedup. The lines of the Miller loop only depend on the `G2` point, so there is
+/// nothing to precompute on the `G1` side beyond the identity check. If the `G2` side
+/// is fixed, use `G2Prepared` instead.
+#[derive(Copy, Clone, Debug)]
+pub struct G1Prepared {
+    pub(crate) point: G1Affine,
+    infinity: bool,
+}
+
+impl From<G1Affine> for G1Prepared {
+    fn from(affine: G1Affine) -> Self {
+        G1Prepared {
+            point: affine,
+            infinity: affine.is_identity().into(),
+        }
+    }
+}
+
+impl G1Prepared {
+    /// Returns true if the prepared point is the point at infinity.
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity as u8)
+    }
+}
+
 impl PairingCurveAffine for G1Affine {
     type Pair = G2Affine;
     type PairingResult = Gt;
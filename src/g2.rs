@@ -36,6 +36,49 @@ use crate::{fp2::Fp2, util, Bls12, Engine, G1Affine, Gt, PairingCurveAffine, Sca
 #[repr(transparent)]
 pub struct G2Affine(pub(crate) blst_p2_affine);
 
+/// `1 / (u + 1)^((p - 1) / 3)`, the coefficient of the `x` coordinate in `ψ`.
+const PSI_COEFF_X: Fp2 = Fp2(blst_fp2 {
+    fp: [
+        blst_fp { l: [0u64; 6] },
+        blst_fp {
+            l: [
+                0x890d_c9e4_8675_45c3,
+                0x2af3_2253_3285_a5d5,
+                0x5088_0866_309b_7e2c,
+                0xa20d_1b8c_7e88_1024,
+                0x14e4_f04f_e2db_9068,
+                0x14e5_6d3f_1564_853a,
+            ],
+        },
+    ],
+});
+
+/// `1 / (u + 1)^((p - 1) / 2)`, the coefficient of the `y` coordinate in `ψ`.
+const PSI_COEFF_Y: Fp2 = Fp2(blst_fp2 {
+    fp: [
+        blst_fp {
+            l: [
+                0x3e2f_585d_a55c_9ad1,
+                0x4294_213d_86c1_8183,
+                0x3828_44c8_8b62_3732,
+                0x92ad_2afd_1910_3e18,
+                0x1d79_4e4f_ac7c_f0b9,
+                0x0bd5_92fc_7d82_5ec8,
+            ],
+        },
+        blst_fp {
+            l: [
+                0x7bcf_a7a2_5aa3_0fda,
+                0xdc17_dec1_2a92_7e7c,
+                0x2f08_8dd8_6b4e_bef1,
+                0xd1ca_2087_da74_d4a7,
+                0x2da2_5966_96ce_bc1d,
+                0x0e2b_7eed_bbfd_87d2,
+            ],
+        },
+    ],
+});
+
 const COMPRESSED_SIZE: usize = 96;
 const UNCOMPRESSED_SIZE: usize = 192;
 
@@ -752,6 +795,57 @@ impl G2Projective {
         G2Projective(out)
     }
 
+    /// The untwist-Frobenius-twist endomorphism `ψ`, which acts on $\mathbb{G}_2$ as
+    /// multiplication by the BLS parameter `x`.
+    fn psi(&self) -> G2Projective {
+        let mut x = self.x();
+        let mut y = self.y();
+        let mut z = self.z();
+        x.frobenius_map(1);
+        y.frobenius_map(1);
+        z.frobenius_map(1);
+        G2Projective::from_raw_unchecked(x * PSI_COEFF_X, y * PSI_COEFF_Y, z)
+    }
+
+    /// Multiplies `self` by `scalar` using the endomorphism `ψ`.
+    ///
+    /// The scalar is split with [`Scalar::decompose_glv`] into four 64-bit digits
+    /// `k_i`, so `scalar * P = sum(k_i * (-ψ)^i(P))`. The four products are computed
+    /// together in 64 doublings, adding an entry from a table of the 16 subset sums
+    /// of `(-ψ)^i(P)` at each step. Both the decomposition and the table lookups run
+    /// in constant time.
+    pub fn mul_glv(&self, scalar: &Scalar) -> G2Projective {
+        let digits = scalar.decompose_glv();
+
+        let mut bases = [*self; 4];
+        for i in 1..4 {
+            bases[i] = -bases[i - 1].psi();
+        }
+
+        let mut table = [G2Projective::IDENTITY; 16];
+        for (i, base) in bases.iter().enumerate() {
+            let bit = 1 << i;
+            for j in 0..bit {
+                table[bit + j] = table[j] + base;
+            }
+        }
+
+        let mut acc = G2Projective::IDENTITY;
+        for bit in (0..64).rev() {
+            acc = acc.double();
+            let index = digits
+                .iter()
+                .enumerate()
+                .fold(0u8, |index, (i, d)| index | ((((d >> bit) & 1) as u8) << i));
+            let mut addend = G2Projective::IDENTITY;
+            for (j, entry) in table.iter().enumerate() {
+                addend.conditional_assign(entry, (j as u8).ct_eq(&index));
+            }
+            acc += addend;
+        }
+        acc
+    }
+
     pub fn from_raw_unchecked(x: Fp2, y: Fp2, z: Fp2) -> Self {
         let raw = blst_p2 {
             x: x.0,
@@ -986,6 +1080,26 @@ impl G2Prepared {
     pub fn is_identity(&self) -> Choice {
         Choice::from(self.infinity as u8)
     }
+
+    /// Deserializes a compressed point, see [`G2Affine::from_compressed`], and
+    /// prepares it. Returns `None` if the point is invalid.
+    pub fn from_compressed_bytes(bytes: &[u8; COMPRESSED_SIZE]) -> Option<Self> {
+        Option::<G2Affine>::from(G2Affine::from_compressed(bytes)).map(Self::from)
+    }
+
+    /// Deserializes an uncompressed point, see [`G2Affine::from_uncompressed`], and
+    /// prepares it. Returns `None` if the point is invalid.
+    pub fn from_uncompressed_bytes(bytes: &[u8; UNCOMPRESSED_SIZE]) -> Option<Self> {
+        Option::<G2Affine>::from(G2Affine::from_uncompressed(bytes)).map(Self::from)
+    }
+
+    /// Computes the pairing of `g1` with this point. Prepare a fixed `G2Affine`
+    /// once and reuse it to avoid recomputing its Miller loop lines for every `G1`.
+    pub fn pairing_with(&self, g1: &G1Affine) -> Gt {
+        use pairing_lib::MillerLoopResult as _;
+
+        crate::multi_miller_loop(&[(g1, self)]).final_exponentiation()
+    }
 }
 
 impl PairingCurveAffine for G2Affine {
@@ -1061,11 +1175,74 @@ mod tests {
 
     use super::*;
 
-    use crate::fp::Fp;
+    use crate::{fp::Fp, G1Projective};
     use ff::Field;
     use rand_core::SeedableRng;
     use rand_xorshift::XorShiftRng;
 
+    #[test]
+    fn test_prepared_from_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g1 = G1Projective::random(&mut rng).to_affine();
+        for g2 in [
+            G2Affine::generator(),
+            G2Projective::random(&mut rng).to_affine(),
+        ] {
+            let expected =
+                G2Prepared::from(G2Affine::from_compressed(&g2.to_compressed()).unwrap());
+
+            let prepared = G2Prepared::from_compressed_bytes(&g2.to_compressed()).unwrap();
+            assert_eq!(prepared.pairing_with(&g1), expected.pairing_with(&g1));
+            assert_eq!(prepared.lines, expected.lines);
+
+            let prepared = G2Prepared::from_uncompressed_bytes(&g2.to_uncompressed()).unwrap();
+            assert_eq!(prepared.pairing_with(&g1), expected.pairing_with(&g1));
+            assert_eq!(prepared.lines, expected.lines);
+        }
+
+        let prepared =
+            G2Prepared::from_compressed_bytes(&G2Affine::identity().to_compressed()).unwrap();
+        assert!(bool::from(prepared.is_identity()));
+
+        let mut bytes = G2Affine::generator().to_compressed();
+        bytes[95] ^= 1;
+        assert!(G2Prepared::from_compressed_bytes(&bytes).is_none());
+        let mut bytes = G2Affine::generator().to_uncompressed();
+        bytes[191] ^= 1;
+        assert!(G2Prepared::from_uncompressed_bytes(&bytes).is_none());
+    }
+
+    #[test]
+    fn test_prepared_pairing_with() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g2 = G2Affine::generator();
+        let prepared = G2Prepared::from(g2);
+        assert_eq!(
+            prepared.pairing_with(&G1Affine::generator()),
+            crate::pairing(&G1Affine::generator(), &g2)
+        );
+
+        let g2 = G2Projective::random(&mut rng).to_affine();
+        let prepared = G2Prepared::from(g2);
+        for _ in 0..5 {
+            let g1 = G1Projective::random(&mut rng).to_affine();
+            assert_eq!(prepared.pairing_with(&g1), crate::pairing(&g1, &g2));
+        }
+        assert_eq!(prepared.pairing_with(&G1Affine::identity()), Gt::identity());
+        assert_eq!(
+            G2Prepared::from(G2Affine::identity()).pairing_with(&G1Affine::generator()),
+            Gt::identity()
+        );
+    }
+
     #[test]
     fn curve_tests() {
         let mut rng = XorShiftRng::from_seed([
@@ -1603,6 +1780,30 @@ mod tests {
         assert_eq!(naive, pippenger);
     }
 
+    #[test]
+    fn test_mul_glv() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let x = -Scalar::from(0xd201_0000_0001_0000u64);
+        let g = G2Projective::GENERATOR;
+        assert_eq!(g.psi(), g * x);
+
+        let mut points = vec![G2Projective::IDENTITY, g];
+        points.extend((0..10).map(|_| G2Projective::random(&mut rng)));
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE, x];
+        scalars.extend((0..10).map(|_| Scalar::random(&mut rng)));
+
+        for p in points.iter() {
+            assert_eq!(p.psi(), p * x);
+            for s in scalars.iter() {
+                assert_eq!(p.mul_glv(s), p * s);
+            }
+        }
+    }
+
     #[test]
     fn test_hex() {
         let g1 = G2Projective::GENERATOR;
@@ -1,18 +1,26 @@
+#[cfg(feature = "ark")]
+mod ark;
+
 use core::{
     borrow::Borrow,
-    iter::Sum,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use std::fmt::{self, Formatter, LowerHex, UpperHex};
+use std::sync::OnceLock;
 
 use arrayref::array_ref;
 use blst::*;
 use ff::Field;
-use group::{Group, GroupEncoding};
+use group::{Curve, Group, GroupEncoding};
 use rand_core::RngCore;
-use subtle::{Choice, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
-use crate::{fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6, traits::Compress, Scalar};
+use crate::{
+    fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6, multi_miller_loop, pairing, traits::Compress, G1Affine,
+    G1Projective, G2Affine, G2Prepared, G2Projective, Scalar, BLS_X,
+};
 
 /// This is an element of $\mathbb{G}_T$, the target group of the pairing function. As with
 /// $\mathbb{G}_1$ and $\mathbb{G}_2$ this group has order $q$.
@@ -29,6 +37,11 @@ impl fmt::Display for Gt {
     }
 }
 
+/// Wraps an `Fp12` as a `Gt` element without any validation.
+///
+/// The result is only meaningful if `fp12` is already in the order-`q` subgroup
+/// of $\mathbb{F}_{q^{12}}^*$, e.g. the output of a final exponentiation. Use
+/// [`Gt::try_from_fp12`] for values that come from elsewhere.
 impl From<Fp12> for Gt {
     fn from(fp12: Fp12) -> Self {
         Gt(fp12)
@@ -43,6 +56,9 @@ impl From<Gt> for Fp12 {
 
 impl Eq for Gt {}
 
+/// Compares the internal `Fp12` limbs, which are canonical for every value produced by
+/// this crate. Values constructed from unreduced limbs, e.g. via `From<Fp12>`, must be
+/// [normalized](Gt::normalize) first.
 impl PartialEq for Gt {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -56,9 +72,7 @@ impl Neg for &Gt {
     #[inline]
     fn neg(self) -> Gt {
         // The element is unitary, so we just conjugate.
-        let mut res = *self;
-        res.0.conjugate();
-        res
+        self.conjugate()
     }
 }
 
@@ -71,6 +85,8 @@ impl Neg for Gt {
     }
 }
 
+/// $\mathbb{G}_T$ is written additively, so `a + b` is the product of the
+/// underlying `Fp12` elements.
 impl Add<&Gt> for &Gt {
     type Output = Gt;
 
@@ -109,7 +125,7 @@ impl Mul<&Scalar> for &Gt {
             .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
             .skip(1)
         {
-            acc = acc.double();
+            acc.double_assign();
             if bit {
                 acc += self;
             }
@@ -143,6 +159,8 @@ impl Mul<Gt> for &Gt {
     }
 }
 
+/// `a * b` is the same `Fp12` multiplication as `a + b`, see [`Gt::product`].
+/// Multiplying by a [`Scalar`] instead is repeated addition, i.e. exponentiation.
 impl Mul for Gt {
     type Output = Gt;
 
@@ -189,6 +207,19 @@ where
     }
 }
 
+/// The same as [`Sum`], see [`Gt::product_of`].
+impl<T> Product<T> for Gt
+where
+    T: Borrow<Gt>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        Self::product_of(iter)
+    }
+}
+
 impl Group for Gt {
     type Scalar = Scalar;
 
@@ -212,124 +243,13 @@ impl Group for Gt {
     }
 
     fn generator() -> Self {
-        Gt(Fp12::new(
-            Fp6::new(
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0x1972_e433_a01f_85c5,
-                        0x97d3_2b76_fd77_2538,
-                        0xc8ce_546f_c96b_cdf9,
-                        0xcef6_3e73_66d4_0614,
-                        0xa611_3427_8184_3780,
-                        0x13f3_448a_3fc6_d825,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0xd263_31b0_2e9d_6995,
-                        0x9d68_a482_f779_7e7d,
-                        0x9c9b_2924_8d39_ea92,
-                        0xf480_1ca2_e131_07aa,
-                        0xa16c_0732_bdbc_b066,
-                        0x083c_a4af_ba36_0478,
-                    ]),
-                ),
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0x59e2_61db_0916_b641,
-                        0x2716_b6f4_b23e_960d,
-                        0xc8e5_5b10_a0bd_9c45,
-                        0x0bdb_0bd9_9c4d_eda8,
-                        0x8cf8_9ebf_57fd_aac5,
-                        0x12d6_b792_9e77_7a5e,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0x5fc8_5188_b0e1_5f35,
-                        0x34a0_6e3a_8f09_6365,
-                        0xdb31_26a6_e02a_d62c,
-                        0xfc6f_5aa9_7d9a_990b,
-                        0xa12f_55f5_eb89_c210,
-                        0x1723_703a_926f_8889,
-                    ]),
-                ),
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0x9358_8f29_7182_8778,
-                        0x43f6_5b86_11ab_7585,
-                        0x3183_aaf5_ec27_9fdf,
-                        0xfa73_d7e1_8ac9_9df6,
-                        0x64e1_76a6_a64c_99b0,
-                        0x179f_a78c_5838_8f1f,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0x672a_0a11_ca2a_ef12,
-                        0x0d11_b9b5_2aa3_f16b,
-                        0xa444_12d0_699d_056e,
-                        0xc01d_0177_221a_5ba5,
-                        0x66e0_cede_6c73_5529,
-                        0x05f5_a71e_9fdd_c339,
-                    ]),
-                ),
-            ),
-            Fp6::new(
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0xd30a_88a1_b062_c679,
-                        0x5ac5_6a5d_35fc_8304,
-                        0xd0c8_34a6_a81f_290d,
-                        0xcd54_30c2_da37_07c7,
-                        0xf0c2_7ff7_8050_0af0,
-                        0x0924_5da6_e2d7_2eae,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0x9f2e_0676_791b_5156,
-                        0xe2d1_c823_4918_fe13,
-                        0x4c9e_459f_3c56_1bf4,
-                        0xa3e8_5e53_b9d3_e3c1,
-                        0x820a_121e_21a7_0020,
-                        0x15af_6183_41c5_9acc,
-                    ]),
-                ),
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0x7c95_658c_2499_3ab1,
-                        0x73eb_3872_1ca8_86b9,
-                        0x5256_d749_4774_34bc,
-                        0x8ba4_1902_ea50_4a8b,
-                        0x04a3_d3f8_0c86_ce6d,
-                        0x18a6_4a87_fb68_6eaa,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0xbb83_e71b_b920_cf26,
-                        0x2a52_77ac_92a7_3945,
-                        0xfc0e_e59f_94f0_46a0,
-                        0x7158_cdf3_7860_58f7,
-                        0x7cc1_061b_82f9_45f6,
-                        0x03f8_47aa_9fdb_e567,
-                    ]),
-                ),
-                Fp2::new(
-                    Fp::from_raw_unchecked([
-                        0x8078_dba5_6134_e657,
-                        0x1cd7_ec9a_4399_8a6e,
-                        0xb1aa_599a_1a99_3766,
-                        0xc9a0_f62f_0842_ee44,
-                        0x8e15_9be3_b605_dffa,
-                        0x0c86_ba0d_4af1_3fc2,
-                    ]),
-                    Fp::from_raw_unchecked([
-                        0xe80f_f2a0_6a52_ffb1,
-                        0x7694_ca48_721a_906c,
-                        0x7583_183e_03b0_8514,
-                        0xf567_afdd_40ce_e4e2,
-                        0x9a6d_96d2_e526_a5fc,
-                        0x197e_9f49_861f_2242,
-                    ]),
-                ),
-            ),
-        ))
+        Self::GENERATOR
     }
 
     fn is_identity(&self) -> Choice {
-        self.0.ct_eq(&Self::identity().0)
+        // Compare with the `Fp12::ONE` constant directly instead of building
+        // the identity element.
+        self.0.ct_eq(&Fp12::ONE)
     }
 
     #[must_use]
@@ -338,6 +258,9 @@ impl Group for Gt {
     }
 }
 
+/// The encoding is the twelve `Fp` coefficients, from `c0.c0.c0` to `c1.c2.c1`,
+/// each as 48 big-endian bytes. See [`Gt::to_bytes_le`] for the same ordering with
+/// little-endian coefficients, which is what [`Compress`] uses.
 impl GroupEncoding for Gt {
     type Repr = GtRepr;
 
@@ -438,18 +361,7 @@ impl GroupEncoding for Gt {
 
     fn to_bytes(&self) -> Self::Repr {
         let mut output = [0u8; Self::BYTES];
-        output[..48].copy_from_slice(&self.0.c0().c0().c0().to_bytes_be());
-        output[48..96].copy_from_slice(&self.0.c0().c0().c1().to_bytes_be());
-        output[96..144].copy_from_slice(&self.0.c0().c1().c0().to_bytes_be());
-        output[144..192].copy_from_slice(&self.0.c0().c1().c1().to_bytes_be());
-        output[192..240].copy_from_slice(&self.0.c0().c2().c0().to_bytes_be());
-        output[240..288].copy_from_slice(&self.0.c0().c2().c1().to_bytes_be());
-        output[288..336].copy_from_slice(&self.0.c1().c0().c0().to_bytes_be());
-        output[336..384].copy_from_slice(&self.0.c1().c0().c1().to_bytes_be());
-        output[384..432].copy_from_slice(&self.0.c1().c1().c0().to_bytes_be());
-        output[432..480].copy_from_slice(&self.0.c1().c1().c1().to_bytes_be());
-        output[480..528].copy_from_slice(&self.0.c1().c2().c0().to_bytes_be());
-        output[528..Self::BYTES].copy_from_slice(&self.0.c1().c2().c1().to_bytes_be());
+        Fp::batch_to_bytes_be(&self.coefficients(), &mut output);
         GtRepr(output)
     }
 }
@@ -474,7 +386,7 @@ impl UpperHex for Gt {
     }
 }
 
-impl subtle::ConditionallySelectable for Gt {
+impl ConditionallySelectable for Gt {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Gt(Fp12::conditional_select(&a.0, &b.0, choice))
     }
@@ -494,6 +406,11 @@ impl_from_bytes!(Gt, |p: &Gt| p.to_bytes().0, |arr: &[u8]| {
 });
 
 /// Compressed representation of `Fp12`.
+///
+/// An element $$c_0 + c_1 w$$ of $\mathbb{G}_T$ is compressed to the unique
+/// $$(c_0 + 1) / c_1$$ in $\mathbb{F}_{p^6}$, so equal elements always have equal
+/// compressed forms and `==` is mathematical equality. The identity has
+/// $$c_1 = 0$$ and no compressed form, see [`Gt::compress`].
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[repr(transparent)]
 pub struct GtCompressed(pub(crate) Fp6);
@@ -501,8 +418,188 @@ pub struct GtCompressed(pub(crate) Fp6);
 impl Gt {
     /// The identity element
     pub const IDENTITY: Self = Self(Fp12::ONE);
+    /// The fixed generator, the pairing of the $\mathbb{G}_1$ and $\mathbb{G}_2$ generators.
+    pub const GENERATOR: Self = Gt(Fp12::new(
+        Fp6::new(
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0x1972_e433_a01f_85c5,
+                    0x97d3_2b76_fd77_2538,
+                    0xc8ce_546f_c96b_cdf9,
+                    0xcef6_3e73_66d4_0614,
+                    0xa611_3427_8184_3780,
+                    0x13f3_448a_3fc6_d825,
+                ]),
+                Fp::from_raw_unchecked([
+                    0xd263_31b0_2e9d_6995,
+                    0x9d68_a482_f779_7e7d,
+                    0x9c9b_2924_8d39_ea92,
+                    0xf480_1ca2_e131_07aa,
+                    0xa16c_0732_bdbc_b066,
+                    0x083c_a4af_ba36_0478,
+                ]),
+            ),
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0x59e2_61db_0916_b641,
+                    0x2716_b6f4_b23e_960d,
+                    0xc8e5_5b10_a0bd_9c45,
+                    0x0bdb_0bd9_9c4d_eda8,
+                    0x8cf8_9ebf_57fd_aac5,
+                    0x12d6_b792_9e77_7a5e,
+                ]),
+                Fp::from_raw_unchecked([
+                    0x5fc8_5188_b0e1_5f35,
+                    0x34a0_6e3a_8f09_6365,
+                    0xdb31_26a6_e02a_d62c,
+                    0xfc6f_5aa9_7d9a_990b,
+                    0xa12f_55f5_eb89_c210,
+                    0x1723_703a_926f_8889,
+                ]),
+            ),
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0x9358_8f29_7182_8778,
+                    0x43f6_5b86_11ab_7585,
+                    0x3183_aaf5_ec27_9fdf,
+                    0xfa73_d7e1_8ac9_9df6,
+                    0x64e1_76a6_a64c_99b0,
+                    0x179f_a78c_5838_8f1f,
+                ]),
+                Fp::from_raw_unchecked([
+                    0x672a_0a11_ca2a_ef12,
+                    0x0d11_b9b5_2aa3_f16b,
+                    0xa444_12d0_699d_056e,
+                    0xc01d_0177_221a_5ba5,
+                    0x66e0_cede_6c73_5529,
+                    0x05f5_a71e_9fdd_c339,
+                ]),
+            ),
+        ),
+        Fp6::new(
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0xd30a_88a1_b062_c679,
+                    0x5ac5_6a5d_35fc_8304,
+                    0xd0c8_34a6_a81f_290d,
+                    0xcd54_30c2_da37_07c7,
+                    0xf0c2_7ff7_8050_0af0,
+                    0x0924_5da6_e2d7_2eae,
+                ]),
+                Fp::from_raw_unchecked([
+                    0x9f2e_0676_791b_5156,
+                    0xe2d1_c823_4918_fe13,
+                    0x4c9e_459f_3c56_1bf4,
+                    0xa3e8_5e53_b9d3_e3c1,
+                    0x820a_121e_21a7_0020,
+                    0x15af_6183_41c5_9acc,
+                ]),
+            ),
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0x7c95_658c_2499_3ab1,
+                    0x73eb_3872_1ca8_86b9,
+                    0x5256_d749_4774_34bc,
+                    0x8ba4_1902_ea50_4a8b,
+                    0x04a3_d3f8_0c86_ce6d,
+                    0x18a6_4a87_fb68_6eaa,
+                ]),
+                Fp::from_raw_unchecked([
+                    0xbb83_e71b_b920_cf26,
+                    0x2a52_77ac_92a7_3945,
+                    0xfc0e_e59f_94f0_46a0,
+                    0x7158_cdf3_7860_58f7,
+                    0x7cc1_061b_82f9_45f6,
+                    0x03f8_47aa_9fdb_e567,
+                ]),
+            ),
+            Fp2::new(
+                Fp::from_raw_unchecked([
+                    0x8078_dba5_6134_e657,
+                    0x1cd7_ec9a_4399_8a6e,
+                    0xb1aa_599a_1a99_3766,
+                    0xc9a0_f62f_0842_ee44,
+                    0x8e15_9be3_b605_dffa,
+                    0x0c86_ba0d_4af1_3fc2,
+                ]),
+                Fp::from_raw_unchecked([
+                    0xe80f_f2a0_6a52_ffb1,
+                    0x7694_ca48_721a_906c,
+                    0x7583_183e_03b0_8514,
+                    0xf567_afdd_40ce_e4e2,
+                    0x9a6d_96d2_e526_a5fc,
+                    0x197e_9f49_861f_2242,
+                ]),
+            ),
+        ),
+    ));
     /// The number of bytes needed to represent this element.
     pub const BYTES: usize = 576;
+    /// Writes the [`GroupEncoding`] encodings of `elements` into `out`, one after another.
+    ///
+    /// Panics if `out` is not exactly `Gt::BYTES * elements.len()` bytes long.
+    pub fn batch_to_bytes(elements: &[Gt], out: &mut [u8]) {
+        assert_eq!(out.len(), Self::BYTES * elements.len());
+        let coefficients = elements
+            .iter()
+            .flat_map(|e| e.coefficients())
+            .collect::<Vec<_>>();
+        Fp::batch_to_bytes_be(&coefficients, out);
+    }
+
+    /// Serializes `self` as the twelve `Fp` coefficients in the same order as
+    /// [`GroupEncoding`], but with each coefficient as 48 little-endian bytes.
+    pub fn to_bytes_le(&self) -> [u8; Self::BYTES] {
+        let mut output = [0u8; Self::BYTES];
+        for (chunk, c) in output.chunks_exact_mut(48).zip(self.coefficients().iter()) {
+            chunk.copy_from_slice(&c.to_bytes_le());
+        }
+        output
+    }
+
+    /// Deserializes the output of [`Gt::to_bytes_le`], failing if a coefficient is
+    /// not a canonical `Fp` element. Like [`GroupEncoding::from_bytes`], this does
+    /// not check that the element is in $\mathbb{G}_T$.
+    pub fn from_bytes_le(bytes: &[u8; Self::BYTES]) -> CtOption<Gt> {
+        let mut is_some = Choice::from(1u8);
+        let mut c = [Fp::ZERO; 12];
+        for (i, c) in c.iter_mut().enumerate() {
+            let fp = Fp::from_bytes_le(array_ref![bytes, i * 48, 48]);
+            is_some &= fp.is_some();
+            *c = fp.unwrap_or(Fp::ZERO);
+        }
+
+        let c0 = Fp6::new(
+            Fp2::new(c[0], c[1]),
+            Fp2::new(c[2], c[3]),
+            Fp2::new(c[4], c[5]),
+        );
+        let c1 = Fp6::new(
+            Fp2::new(c[6], c[7]),
+            Fp2::new(c[8], c[9]),
+            Fp2::new(c[10], c[11]),
+        );
+        CtOption::new(Gt(Fp12::new(c0, c1)), is_some)
+    }
+
+    /// The twelve `Fp` coefficients in the order used by [`GroupEncoding`].
+    fn coefficients(&self) -> [Fp; 12] {
+        [
+            self.0.c0().c0().c0(),
+            self.0.c0().c0().c1(),
+            self.0.c0().c1().c0(),
+            self.0.c0().c1().c1(),
+            self.0.c0().c2().c0(),
+            self.0.c0().c2().c1(),
+            self.0.c1().c0().c0(),
+            self.0.c1().c0().c1(),
+            self.0.c1().c1().c0(),
+            self.0.c1().c1().c1(),
+            self.0.c1().c2().c0(),
+            self.0.c1().c2().c1(),
+        ]
+    }
+
     /// Compress this point. Returns `None` if the element is not in the cyclomtomic subgroup.
     pub fn compress(&self) -> Option<GtCompressed> {
         // Use torus-based compression from Section 4.1 in
@@ -515,21 +612,525 @@ impl Gt {
         b.map(GtCompressed)
     }
 
+    /// Uncompresses every element of `compressed`, sharing a single inversion
+    /// between them. Returns `None` if any element fails to uncompress, see
+    /// [`GtCompressed::uncompress`].
+    pub fn batch_uncompress(compressed: &[GtCompressed]) -> Option<Vec<Gt>> {
+        use ff::BatchInvert;
+
+        let fp6_neg_one = Fp6::from(1).neg();
+        // The `c1` coefficient is never zero so every element is invertible
+        let mut t: Vec<Fp12> = compressed
+            .iter()
+            .map(|c| Fp12::new(c.0, fp6_neg_one))
+            .collect();
+        t.iter_mut().batch_invert();
+
+        compressed
+            .iter()
+            .zip(t.iter())
+            .map(|(c, t)| {
+                let g = Gt(Fp12::new(c.0, Fp6::from(1)) * t);
+                g.is_in_subgroup().then_some(g)
+            })
+            .collect()
+    }
+
+    /// Reduces each `Fp` coefficient to its canonical Montgomery form, so that
+    /// equality comparisons are meaningful for values built from unreduced limbs.
+    pub fn normalize(&mut self) {
+        let mut bytes = [0u8; 48];
+        for fp6 in (self.0).0.fp6.iter_mut() {
+            for fp2 in fp6.fp2.iter_mut() {
+                for fp in fp2.fp.iter_mut() {
+                    unsafe {
+                        blst_lendian_from_fp(bytes.as_mut_ptr(), fp);
+                        blst_fp_from_lendian(fp, bytes.as_ptr());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Serializes the compression of this element into the same 288 bytes written by
+    /// [`Compress::write_compressed`]. Returns `None` if [`Gt::compress`] does.
+    pub fn to_compressed_bytes(&self) -> Option<[u8; GtCompressed::BYTES]> {
+        self.compress().map(|c| c.to_bytes_le())
+    }
+
+    /// Deserializes the output of [`Gt::to_compressed_bytes`], returns `None` if the
+    /// bytes are not the compression of an element of $\mathbb{G}_T$.
+    pub fn from_compressed_bytes(bytes: &[u8; GtCompressed::BYTES]) -> Option<Gt> {
+        GtCompressed::from_bytes_le(bytes)?.uncompress()
+    }
+
+    /// Serializes this element prefixed with a one byte tag, `0` followed by the
+    /// [`GroupEncoding`] bytes or `1` followed by the [`Gt::to_compressed_bytes`] bytes.
+    ///
+    /// If `compressed` is set but the element has no compressed form, such as the
+    /// identity, the uncompressed form is used.
+    pub fn to_tagged_bytes(&self, compressed: bool) -> Vec<u8> {
+        let mut out = Vec::with_capacity(1 + Self::BYTES);
+        let compressed_bytes = if compressed {
+            self.to_compressed_bytes()
+        } else {
+            None
+        };
+        match compressed_bytes {
+            Some(bytes) => {
+                out.push(1);
+                out.extend_from_slice(&bytes);
+            }
+            None => {
+                out.push(0);
+                out.extend_from_slice(self.to_bytes().as_ref());
+            }
+        }
+        out
+    }
+
+    /// Deserializes the output of [`Gt::to_tagged_bytes`], failing if the tag is
+    /// unknown, the length does not match the tag, or the element is not in
+    /// $\mathbb{G}_T$.
+    pub fn from_tagged_bytes(bytes: &[u8]) -> CtOption<Gt> {
+        let gt = match bytes.split_first() {
+            Some((0, rest)) => <&[u8; Self::BYTES]>::try_from(rest)
+                .ok()
+                .and_then(|b| Option::<Gt>::from(Self::from_bytes(&GtRepr(*b))))
+                .filter(|g| g.is_in_subgroup()),
+            Some((1, rest)) => <&[u8; GtCompressed::BYTES]>::try_from(rest)
+                .ok()
+                .and_then(Self::from_compressed_bytes),
+            _ => None,
+        };
+        CtOption::new(gt.unwrap_or_default(), Choice::from(gt.is_some() as u8))
+    }
+
+    /// Deserializes either encoding depending on the length of `bytes`, 576 bytes
+    /// as the [`GroupEncoding`] bytes and 288 bytes as the
+    /// [`Gt::to_compressed_bytes`] bytes. Fails for any other length, or if the
+    /// element is not in $\mathbb{G}_T$.
+    pub fn from_bytes_any(bytes: &[u8]) -> CtOption<Gt> {
+        let gt = match bytes.len() {
+            Self::BYTES => <&[u8; Self::BYTES]>::try_from(bytes)
+                .ok()
+                .and_then(|b| Option::<Gt>::from(Self::from_bytes(&GtRepr(*b))))
+                .filter(|g| g.is_in_subgroup()),
+            GtCompressed::BYTES => <&[u8; GtCompressed::BYTES]>::try_from(bytes)
+                .ok()
+                .and_then(Self::from_compressed_bytes),
+            _ => None,
+        };
+        CtOption::new(gt.unwrap_or_default(), Choice::from(gt.is_some() as u8))
+    }
+
+    /// Samples an element by pairing a random $\mathbb{G}_1$ point with a random
+    /// $\mathbb{G}_2$ point, which is always in the subgroup.
+    ///
+    /// Unlike [`Group::random`], this does not sample an arbitrary `Fp12` element
+    /// and map it through the final exponentiation.
+    pub fn random_subgroup(mut rng: impl RngCore) -> Gt {
+        let p = G1Projective::random(&mut rng).to_affine();
+        let q = G2Projective::random(&mut rng).to_affine();
+        pairing(&p, &q)
+    }
+
+    /// Asserts that $$e(a \cdot g, b \cdot h) = (a b) \cdot e(g, h)$$ for the
+    /// generators `g` and `h`, to sanity check how scalars are handled.
+    ///
+    /// This only runs with debug assertions enabled, and is a no-op otherwise.
+    pub fn assert_bilinear(a: &Scalar, b: &Scalar) {
+        Self::assert_bilinear_with(a, b, &(a * b));
+    }
+
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn assert_bilinear_with(a: &Scalar, b: &Scalar, ab: &Scalar) {
+        #[cfg(debug_assertions)]
+        {
+            let lhs = pairing(
+                &(G1Projective::generator() * a).to_affine(),
+                &(G2Projective::generator() * b).to_affine(),
+            );
+            assert_eq!(lhs, Gt::generator() * ab, "pairing is not bilinear");
+        }
+    }
+
+    /// Computes $$\sum_{i=1}^n e(a_i, b_i)$$ given a series of terms
+    /// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
+    ///
+    /// The Miller loops of all terms are accumulated and only one final
+    /// exponentiation is performed, instead of one per term.
+    pub fn sum_of_pairings(terms: &[(&G1Affine, &G2Affine)]) -> Gt {
+        use pairing_lib::MillerLoopResult as _;
+
+        let prepared: Vec<G2Prepared> = terms.iter().map(|(_, q)| G2Prepared::from(**q)).collect();
+        let terms: Vec<(&G1Affine, &G2Prepared)> = terms
+            .iter()
+            .zip(prepared.iter())
+            .map(|((p, _), q)| (*p, q))
+            .collect();
+
+        multi_miller_loop(&terms).final_exponentiation()
+    }
+
+    /// Returns `true` if every element of `elements` is in the target group.
+    ///
+    /// Each element is checked individually. Checking a single random linear
+    /// combination instead is not sound here: the cyclotomic subgroup of
+    /// $\mathbb{F}_{p^{12}}^*$ has a cofactor whose smallest prime factor is 4513,
+    /// and elements outside it can have order 2, so an invalid element would slip
+    /// through with probability as high as 1/2.
+    pub fn batch_in_subgroup(elements: &[Gt]) -> bool {
+        elements.iter().all(Gt::is_in_subgroup)
+    }
+
+    /// Converts an `Fp12` into a `Gt` element, returning `None` if it is not in
+    /// the target group.
+    pub fn try_from_fp12(fp12: Fp12) -> CtOption<Gt> {
+        let gt = Gt(fp12);
+        let is_valid = Choice::from(gt.is_in_subgroup() as u8);
+        CtOption::new(gt, is_valid)
+    }
+
     fn is_in_subgroup(&self) -> bool {
         unsafe { blst_fp12_in_group(&(self.0).0) }
     }
 
+    /// Doubles this element in place, the same as [`Group::double`].
+    ///
+    /// This uses a general `Fp12` squaring rather than the cyclotomic one, since
+    /// elements built with `From<Fp12>` or [`GroupEncoding::from_bytes`] need not
+    /// be in the cyclotomic subgroup.
+    pub fn double_assign(&mut self) {
+        self.0 = self.0.square();
+    }
+
+    /// Computes `self * scalar` with a fixed window of `W` bits.
+    ///
+    /// This precomputes a table of `2^W` multiples of `self`, each 576 bytes, and
+    /// then needs one addition per window. Larger windows save additions at the
+    /// cost of memory and setup time. `W` must be between 1 and 16.
+    ///
+    /// This is variable time with respect to `scalar`.
+    pub fn mul_windowed<const W: usize>(&self, scalar: &Scalar) -> Gt {
+        assert!(
+            (1..=16).contains(&W),
+            "window size must be between 1 and 16"
+        );
+
+        let mut table = Vec::with_capacity(1 << W);
+        table.push(Gt::identity());
+        for i in 1..1 << W {
+            table.push(table[i - 1] + self);
+        }
+
+        let bits = scalar.bits_le();
+        let mut acc = Gt::identity();
+        for window in (0..bits.len().div_ceil(W)).rev() {
+            for _ in 0..W {
+                acc.double_assign();
+            }
+            let digit = bits
+                .iter()
+                .skip(window * W)
+                .take(W)
+                .rev()
+                .fold(0, |digit, &bit| (digit << 1) | bit as usize);
+            acc += table[digit];
+        }
+        acc
+    }
+
+    /// Computes `a * x + b * y` with Shamir's trick, sharing the doublings of both
+    /// products and using a joint table of `a`, `b` and `a + b`.
+    ///
+    /// This is variable time with respect to `x` and `y`.
+    pub fn double_exp(a: &Gt, x: &Scalar, b: &Gt, y: &Scalar) -> Gt {
+        let table = [Gt::identity(), *a, *b, a + b];
+        let mut acc = Gt::identity();
+        for (x_bit, y_bit) in x.bits_be().into_iter().zip(y.bits_be()) {
+            acc.double_assign();
+            let index = x_bit as usize | (y_bit as usize) << 1;
+            if index != 0 {
+                acc += table[index];
+            }
+        }
+        acc
+    }
+
+    /// Computes `self * scalar` with [`Gt::mul_windowed`] and a 4-bit window.
+    pub fn mul_by_scalar(&self, scalar: &Scalar) -> Gt {
+        self.mul_windowed::<4>(scalar)
+    }
+
+    /// Computes $$\sum_i s_i \cdot b_i$$ over both the `fixed` bases, which use
+    /// their precomputed tables, and the `variable` bases, which are combined with
+    /// Pippenger's bucket method.
+    ///
+    /// This is variable time with respect to the scalars of the `variable` terms.
+    pub fn multi_exp_mixed(fixed: &[(GtPrecompute, Scalar)], variable: &[(Gt, Scalar)]) -> Gt {
+        let fixed: Gt = fixed.iter().map(|(table, s)| table.exp(s)).sum();
+        fixed + Self::multi_exp_vartime(variable)
+    }
+
+    fn multi_exp_vartime(terms: &[(Gt, Scalar)]) -> Gt {
+        if terms.is_empty() {
+            return Gt::identity();
+        }
+
+        // Roughly the optimal window for `n` terms is `log2(n)`, but no smaller
+        // than 3 bits.
+        let c = (terms.len().ilog2() as usize).max(3);
+        let bits = terms.iter().map(|(_, s)| s.bits_le()).collect::<Vec<_>>();
+        let mut acc = Gt::identity();
+        for window in (0..256usize.div_ceil(c)).rev() {
+            for _ in 0..c {
+                acc.double_assign();
+            }
+
+            let mut buckets = vec![Gt::identity(); (1 << c) - 1];
+            for ((base, _), bits) in terms.iter().zip(bits.iter()) {
+                let digit = bits
+                    .iter()
+                    .skip(window * c)
+                    .take(c)
+                    .rev()
+                    .fold(0, |digit, &bit| (digit << 1) | bit as usize);
+                if digit != 0 {
+                    buckets[digit - 1] += base;
+                }
+            }
+
+            // Bucket `j` has to be counted `j + 1` times, which a running sum from
+            // the top bucket down does with two additions per bucket.
+            let mut running = Gt::identity();
+            for bucket in buckets.iter().rev() {
+                running += bucket;
+                acc += running;
+            }
+        }
+        acc
+    }
+
+    /// Computes `Gt::generator() * s` using the shared [`GtGeneratorTable`].
+    pub fn generator_mul(s: &Scalar) -> Gt {
+        GtGeneratorTable::get().exp(s)
+    }
+
+    /// The identity of $\mathbb{G}_T$ in multiplicative notation, the same as
+    /// [`Group::identity`].
+    pub fn one() -> Gt {
+        Self::identity()
+    }
+
+    /// The group operation in multiplicative notation, the same as `self + rhs`.
+    pub fn mul_group(&self, rhs: &Gt) -> Gt {
+        self + rhs
+    }
+
+    /// The group inverse in multiplicative notation, the same as `-self`.
+    pub fn inverse(&self) -> Gt {
+        -self
+    }
+
+    /// Multiplies the underlying `Fp12` elements of `a` and `b`.
+    ///
+    /// Since $\mathbb{G}_T$ is written additively this is the same as `a + b`,
+    /// and is what `Mul<Gt>` uses.
     pub fn product(a: &Self, b: &Self) -> Self {
         Self(a.0.mul(b.0))
     }
 
+    /// Multiplies the underlying `Fp12` elements of everything in `iter`, starting
+    /// from the identity. This is the same as summing them.
+    pub fn product_of<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Gt>,
+    {
+        iter.into_iter().fold(Self::identity(), |acc, item| {
+            Self::product(&acc, item.borrow())
+        })
+    }
+
     /// Invert this element.
     pub fn invert(&self) -> CtOption<Self> {
         self.0.invert().map(Self)
     }
+
+    /// Returns the conjugate of this element in $\mathbb{F}_{p^{12}}$ over
+    /// $\mathbb{F}_{p^6}$. For elements of the cyclotomic subgroup, which includes
+    /// $\mathbb{G}_T$, this is the inverse and equal to `-self`.
+    pub fn conjugate(&self) -> Gt {
+        let mut res = *self;
+        res.conjugate_assign();
+        res
+    }
+
+    /// Conjugates this element in place, see [`Gt::conjugate`].
+    pub fn conjugate_assign(&mut self) {
+        self.0.conjugate();
+    }
+
+    /// Raises this element to the power `exp`, given as little-endian `u64` limbs,
+    /// using cyclotomic squarings.
+    ///
+    /// This is only correct for elements of the cyclotomic subgroup, which includes
+    /// every pairing output. **This operation is variable time with respect to `exp`.**
+    pub fn cyclotomic_exp(&self, exp: &[u64]) -> Gt {
+        let mut res = Fp12::ONE;
+        let mut found_one = false;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                if found_one {
+                    res = res.cyclotomic_square();
+                }
+                if (limb >> i) & 1 == 1 {
+                    found_one = true;
+                    res *= self.0;
+                }
+            }
+        }
+        Gt(res)
+    }
+
+    /// Raises this element to the power of the BLS parameter `x = -0xd201000000010000`.
+    ///
+    /// Exponentiates by `|x|` with [`Gt::cyclotomic_exp`] and conjugates for the sign,
+    /// so the same cyclotomic subgroup requirement applies.
+    pub fn cyclotomic_exp_by_x(&self) -> Gt {
+        -self.cyclotomic_exp(&[BLS_X])
+    }
+
+    /// Multiplies this element by `scalar` using a width-`window` signed-digit
+    /// recoding from [`Scalar::to_wnaf`], subtracting table entries for negative
+    /// digits since negation is just a conjugation.
+    ///
+    /// This is only correct for elements of the cyclotomic subgroup, which includes
+    /// every pairing output. **This operation is variable time with respect to
+    /// `scalar`**, so it must only be used with public scalars.
+    ///
+    /// Panics if `window` is not in `2..=8`.
+    pub fn mul_naf(&self, scalar: &Scalar, window: usize) -> Gt {
+        let wnaf = scalar.to_wnaf(window);
+
+        // table[i] = (2i + 1) * self
+        let double = Gt(self.0.cyclotomic_square());
+        let mut table = Vec::with_capacity(1 << (window - 2));
+        table.push(*self);
+        for i in 1..1 << (window - 2) {
+            table.push(table[i - 1] + double);
+        }
+
+        let mut acc = Gt::IDENTITY;
+        for &digit in wnaf.iter().rev() {
+            acc = Gt(acc.0.cyclotomic_square());
+            if digit > 0 {
+                acc += table[(digit as usize - 1) / 2];
+            } else if digit < 0 {
+                acc -= table[((-digit) as usize - 1) / 2];
+            }
+        }
+        acc
+    }
+
+    /// Raises this element to the power of a small integer `exp`, returning the
+    /// identity when `exp` is zero.
+    ///
+    /// This is [`Gt::cyclotomic_exp`] over a single limb and is variable time with
+    /// respect to `exp`.
+    pub fn pow_u64(&self, exp: u64) -> Gt {
+        self.cyclotomic_exp(&[exp])
+    }
 }
 
 impl GtCompressed {
+    /// The number of bytes needed to represent this element.
+    pub const BYTES: usize = 288;
+    /// The compressed form of [`Gt::GENERATOR`].
+    pub const GENERATOR: Self = GtCompressed(Fp6::new(
+        Fp2::new(
+            Fp::from_raw_unchecked([
+                0xaf93_aaa7_0ec7_9d9d,
+                0xad2a_1557_a73f_6cea,
+                0x713d_fefd_fedb_3b53,
+                0xf5b2_b7af_796d_a862,
+                0xfd18_1d32_98de_9f0e,
+                0x150e_1916_af42_32ce,
+            ]),
+            Fp::from_raw_unchecked([
+                0x8124_d47a_388a_44e5,
+                0xc4b1_a2ad_2f13_fef0,
+                0x4d5a_f0e1_88db_e124,
+                0x307c_d793_1273_6545,
+                0xc764_6fcd_bb98_6158,
+                0x0f52_218b_8baf_a440,
+            ]),
+        ),
+        Fp2::new(
+            Fp::from_raw_unchecked([
+                0xee38_0b9b_a07a_dafd,
+                0x4e06_eef2_2f1a_e385,
+                0xf63e_783a_d296_1dad,
+                0x7ee5_0ba3_876d_2ee9,
+                0xe821_73f4_a301_80b3,
+                0x0179_5320_8f92_9a52,
+            ]),
+            Fp::from_raw_unchecked([
+                0x4e53_dcaf_89f7_d23f,
+                0xd78d_bef6_59fb_b254,
+                0xf061_1c45_ff97_7925,
+                0x5fdd_d34f_5d9d_3a4b,
+                0xaf65_4965_78be_f490,
+                0x0a21_f07a_15d7_566c,
+            ]),
+        ),
+        Fp2::new(
+            Fp::from_raw_unchecked([
+                0x5764_d301_5deb_fdeb,
+                0xb6a6_9d5f_ebc6_ffb2,
+                0xabcb_88f2_e3c9_e182,
+                0x2873_edb6_e941_b687,
+                0x2523_f84b_d0de_ac79,
+                0x07eb_4225_559c_389d,
+            ]),
+            Fp::from_raw_unchecked([
+                0x9896_1c5c_277b_af58,
+                0x3ef2_5e41_3645_8b27,
+                0xe9eb_7ba2_2656_883b,
+                0x9887_300e_341f_f631,
+                0xc06e_0b04_1239_381e,
+                0x008e_3293_769d_34c3,
+            ]),
+        ),
+    ));
+
+    /// Serializes the six `Fp` coefficients in little-endian order, as used by
+    /// [`Compress`].
+    pub(crate) fn to_bytes_le(&self) -> [u8; Self::BYTES] {
+        let mut output = [0u8; Self::BYTES];
+        output[..48].copy_from_slice(&self.0.c0().c0().to_bytes_le());
+        output[48..96].copy_from_slice(&self.0.c0().c1().to_bytes_le());
+        output[96..144].copy_from_slice(&self.0.c1().c0().to_bytes_le());
+        output[144..192].copy_from_slice(&self.0.c1().c1().to_bytes_le());
+        output[192..240].copy_from_slice(&self.0.c2().c0().to_bytes_le());
+        output[240..Self::BYTES].copy_from_slice(&self.0.c2().c1().to_bytes_le());
+        output
+    }
+
+    /// Deserializes the output of [`GtCompressed::to_bytes_le`], returns `None` if
+    /// a coefficient is not a canonical `Fp` element. This does not check that the
+    /// element uncompresses to a member of $\mathbb{G}_T$.
+    pub(crate) fn from_bytes_le(bytes: &[u8; Self::BYTES]) -> Option<Self> {
+        let fp = |i: usize| Option::<Fp>::from(Fp::from_bytes_le(array_ref![bytes, i * 48, 48]));
+
+        Some(GtCompressed(Fp6::new(
+            Fp2::new(fp(0)?, fp(1)?),
+            Fp2::new(fp(2)?, fp(3)?),
+            Fp2::new(fp(4)?, fp(5)?),
+        )))
+    }
+
     /// Uncompress the element, returns `None` if the element is an invalid compression
     /// format.
     pub fn uncompress(self) -> Option<Gt> {
@@ -549,21 +1150,55 @@ impl GtCompressed {
     }
 }
 
-impl Compress for Gt {
-    fn write_compressed<W: std::io::Write>(self, mut out: W) -> std::io::Result<()> {
-        let c = self.compress().unwrap();
+impl Zeroize for Gt {
+    fn zeroize(&mut self) {
+        for fp6 in (self.0).0.fp6.iter_mut() {
+            for fp2 in fp6.fp2.iter_mut() {
+                fp2.fp[0].l.zeroize();
+                fp2.fp[1].l.zeroize();
+            }
+        }
+    }
+}
 
-        out.write_all(&c.0.c0().c0().to_bytes_le())?;
-        out.write_all(&c.0.c0().c1().to_bytes_le())?;
+impl Zeroize for GtCompressed {
+    fn zeroize(&mut self) {
+        for fp2 in (self.0).0.fp2.iter_mut() {
+            fp2.fp[0].l.zeroize();
+            fp2.fp[1].l.zeroize();
+        }
+    }
+}
 
-        out.write_all(&c.0.c1().c0().to_bytes_le())?;
-        out.write_all(&c.0.c1().c1().to_bytes_le())?;
+impl ConstantTimeEq for GtCompressed {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
 
-        out.write_all(&c.0.c2().c0().to_bytes_le())?;
-        out.write_all(&c.0.c2().c1().to_bytes_le())?;
+impl LowerHex for GtCompressed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for &byte in self.to_bytes_le().iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
 
+impl UpperHex for GtCompressed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for &byte in self.to_bytes_le().iter() {
+            write!(f, "{:02X}", byte)?;
+        }
         Ok(())
     }
+}
+
+impl Compress for Gt {
+    fn write_compressed<W: std::io::Write>(self, mut out: W) -> std::io::Result<()> {
+        let c = self.compress().unwrap();
+        out.write_all(&c.to_bytes_le())
+    }
 
     fn read_compressed<R: std::io::Read>(mut source: R) -> std::io::Result<Self> {
         let mut buffer = [0u8; 48];
@@ -594,6 +1229,137 @@ impl Compress for Gt {
     }
 }
 
+/// A [`Gt`] together with its lazily computed [`GtCompressed`] form.
+///
+/// Useful for long-lived values, such as a verification key, that are serialized
+/// repeatedly, since compressing needs an `Fp6` inversion.
+#[derive(Clone, Debug)]
+pub struct GtCached {
+    value: Gt,
+    compressed: OnceLock<Option<GtCompressed>>,
+}
+
+impl From<Gt> for GtCached {
+    fn from(value: Gt) -> Self {
+        Self::new(value)
+    }
+}
+
+impl GtCached {
+    /// Wrap `value`, its compressed form is computed on first use.
+    pub fn new(value: Gt) -> Self {
+        Self {
+            value,
+            compressed: OnceLock::new(),
+        }
+    }
+
+    /// The wrapped element.
+    pub fn value(&self) -> &Gt {
+        &self.value
+    }
+
+    /// The result of [`Gt::compress`], computed once and then reused.
+    pub fn compressed(&self) -> Option<&GtCompressed> {
+        self.compressed
+            .get_or_init(|| self.value.compress())
+            .as_ref()
+    }
+}
+
+/// A precomputed table of multiples of a fixed base for fast fixed-base scalar
+/// multiplication.
+///
+/// The scalar is split into 64 windows of 4 bits, and window `i` stores
+/// $$j \cdot 2^{4i} \cdot b$$ for every digit `j` and base `b`, so
+/// [`GtPrecompute::exp`] needs only 64 additions and no doublings. The table holds
+/// 1024 elements (about 576 KiB).
+#[derive(Clone, Debug)]
+pub struct GtPrecompute {
+    windows: Vec<[Gt; 16]>,
+}
+
+impl From<Gt> for GtPrecompute {
+    fn from(base: Gt) -> Self {
+        Self::new(&base)
+    }
+}
+
+impl GtPrecompute {
+    const WINDOW: usize = 4;
+    const WINDOWS: usize = 256 / Self::WINDOW;
+
+    /// Build the table for `base`.
+    pub fn new(base: &Gt) -> Self {
+        let mut base = *base;
+        let windows = (0..Self::WINDOWS)
+            .map(|_| {
+                let mut row = [Gt::identity(); 16];
+                for j in 1..16 {
+                    row[j] = row[j - 1] + base;
+                }
+                for _ in 0..Self::WINDOW {
+                    base.double_assign();
+                }
+                row
+            })
+            .collect();
+        Self { windows }
+    }
+
+    /// The base this table was built for.
+    pub fn base(&self) -> Gt {
+        self.windows[0][1]
+    }
+
+    /// Computes $$s \cdot b$$ for the base `b`, in constant time.
+    pub fn exp(&self, s: &Scalar) -> Gt {
+        let bytes = s.to_le_bytes();
+        let mut acc = Gt::identity();
+        for (i, row) in self.windows.iter().enumerate() {
+            let digit = (bytes[i / 2] >> ((i % 2) * Self::WINDOW)) & 0x0f;
+            let mut term = Gt::identity();
+            for (j, entry) in row.iter().enumerate() {
+                term.conditional_assign(entry, (j as u8).ct_eq(&digit));
+            }
+            acc += term;
+        }
+        acc
+    }
+}
+
+/// A [`GtPrecompute`] table for [`Gt::generator`].
+#[derive(Clone, Debug)]
+pub struct GtGeneratorTable {
+    table: GtPrecompute,
+}
+
+impl Default for GtGeneratorTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GtGeneratorTable {
+    /// Build the table, prefer [`GtGeneratorTable::get`] to share one instance.
+    pub fn new() -> Self {
+        Self {
+            table: GtPrecompute::new(&Gt::generator()),
+        }
+    }
+
+    /// The shared table, built on first use.
+    pub fn get() -> &'static Self {
+        static TABLE: OnceLock<GtGeneratorTable> = OnceLock::new();
+        TABLE.get_or_init(Self::new)
+    }
+
+    /// Computes $$s \cdot g$$ for the generator `g`, in constant time.
+    pub fn exp(&self, s: &Scalar) -> Gt {
+        self.table.exp(s)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct GtRepr(pub(crate) [u8; 576]);
 
@@ -615,30 +1381,105 @@ impl AsMut<[u8]> for GtRepr {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    use group::{prime::PrimeCurveAffine, Curve};
-    use pairing_lib::{Engine, MillerLoopResult, MultiMillerLoop};
-    use rand_core::SeedableRng;
-    use rand_xorshift::XorShiftRng;
+/// A [`Gt`] element whose [`GroupEncoding`] is the 288-byte compressed form of
+/// [`Gt::to_compressed_bytes`] instead of the 576-byte uncompressed form.
+///
+/// The identity has no compressed form and is encoded as all zeros. This is the
+/// compression of `-1`, which is not in $\mathbb{G}_T$, so the encoding is
+/// unambiguous.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GtCompressedEncoding(pub Gt);
 
-    use crate::{pairing, Bls12, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective};
+impl From<Gt> for GtCompressedEncoding {
+    fn from(gt: Gt) -> Self {
+        GtCompressedEncoding(gt)
+    }
+}
 
-    #[test]
-    fn test_gt_generator() {
-        assert_eq!(
-            Gt::generator(),
-            pairing(&G1Affine::generator(), &G2Affine::generator()),
-        );
+impl From<GtCompressedEncoding> for Gt {
+    fn from(encoding: GtCompressedEncoding) -> Self {
+        encoding.0
     }
+}
 
-    #[test]
-    fn test_gt_bilinearity() {
-        use crate::Scalar;
+impl GroupEncoding for GtCompressedEncoding {
+    type Repr = GtCompressedRepr;
 
-        let a = Scalar::from_raw([1, 2, 3, 4])
+    /// Deserializes the compressed form, checking that the element is in
+    /// $\mathbb{G}_T$.
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        let gt = if bytes.0.iter().all(|b| *b == 0) {
+            Some(Gt::identity())
+        } else {
+            Gt::from_compressed_bytes(&bytes.0)
+        };
+        CtOption::new(
+            GtCompressedEncoding(gt.unwrap_or_default()),
+            Choice::from(gt.is_some() as u8),
+        )
+    }
+
+    /// Same as [`GroupEncoding::from_bytes`], uncompressing always checks the
+    /// subgroup.
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        GtCompressedRepr(
+            self.0
+                .to_compressed_bytes()
+                .unwrap_or([0u8; GtCompressed::BYTES]),
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct GtCompressedRepr(pub(crate) [u8; GtCompressed::BYTES]);
+
+impl Default for GtCompressedRepr {
+    fn default() -> Self {
+        GtCompressedRepr([0; GtCompressed::BYTES])
+    }
+}
+
+impl AsRef<[u8]> for GtCompressedRepr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for GtCompressedRepr {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use group::{prime::PrimeCurveAffine, Curve};
+    use pairing_lib::{Engine, MillerLoopResult, MultiMillerLoop};
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::Bls12;
+
+    #[test]
+    fn test_gt_generator() {
+        assert_eq!(
+            Gt::generator(),
+            pairing(&G1Affine::generator(), &G2Affine::generator()),
+        );
+    }
+
+    #[test]
+    fn test_gt_bilinearity() {
+        use crate::Scalar;
+
+        let a = Scalar::from_raw([1, 2, 3, 4])
             .unwrap()
             .invert()
             .unwrap()
@@ -668,6 +1509,166 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gt_double_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..5 {
+            let a = Gt::random(&mut rng);
+            let b = Gt::random(&mut rng);
+            let x = Scalar::random(&mut rng);
+            let y = Scalar::random(&mut rng);
+            assert_eq!(Gt::double_exp(&a, &x, &b, &y), a * x + b * y);
+            assert_eq!(Gt::double_exp(&a, &x, &b, &Scalar::ZERO), a * x);
+            assert_eq!(Gt::double_exp(&a, &Scalar::ZERO, &b, &y), b * y);
+            assert_eq!(
+                Gt::double_exp(&a, &Scalar::ZERO, &b, &Scalar::ZERO),
+                Gt::identity()
+            );
+            assert_eq!(Gt::double_exp(&a, &x, &a, &y), a * (x + y));
+        }
+    }
+
+    #[test]
+    fn test_gt_mul_windowed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = Gt::random(&mut rng);
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        scalars.extend((0..3).map(|_| Scalar::random(&mut rng)));
+        for s in &scalars {
+            let expected = g * s;
+            assert_eq!(g.mul_windowed::<1>(s), expected);
+            assert_eq!(g.mul_windowed::<2>(s), expected);
+            assert_eq!(g.mul_windowed::<4>(s), expected);
+            assert_eq!(g.mul_windowed::<5>(s), expected);
+            assert_eq!(g.mul_windowed::<8>(s), expected);
+            assert_eq!(g.mul_by_scalar(s), expected);
+        }
+    }
+
+    #[test]
+    fn test_gt_multi_exp_mixed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let fixed = (0..3)
+            .map(|_| {
+                let base = GtPrecompute::from(Gt::random(&mut rng));
+                (base, Scalar::random(&mut rng))
+            })
+            .collect::<Vec<_>>();
+        let mut variable = (0..10)
+            .map(|_| (Gt::random(&mut rng), Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        variable[3].1 = Scalar::ZERO;
+        variable[4].1 = Scalar::ONE;
+        variable[5].0 = Gt::identity();
+
+        let naive_fixed: Gt = fixed.iter().map(|(t, s)| t.base() * s).sum();
+        let naive_variable: Gt = variable.iter().map(|(b, s)| b * s).sum();
+        let expected = naive_fixed + naive_variable;
+        assert_eq!(Gt::multi_exp_mixed(&fixed, &variable), expected);
+        assert_eq!(Gt::multi_exp_mixed(&fixed, &[]), naive_fixed);
+        assert_eq!(Gt::multi_exp_mixed(&[], &variable), naive_variable);
+        let (base, s) = variable[0];
+        assert_eq!(Gt::multi_exp_mixed(&[], &variable[..1]), base * s);
+        assert_eq!(Gt::multi_exp_mixed(&[], &[]), Gt::identity());
+    }
+
+    #[test]
+    fn test_gt_generator_mul() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Gt::generator_mul(&Scalar::ZERO), Gt::identity());
+        assert_eq!(Gt::generator_mul(&Scalar::ONE), Gt::generator());
+        assert_eq!(Gt::generator_mul(&-Scalar::ONE), -Gt::generator());
+        for _ in 0..10 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(Gt::generator_mul(&s), Gt::generator() * s);
+        }
+    }
+
+    #[test]
+    fn test_gt_batch_in_subgroup() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut elements = (0..5)
+            .map(|_| Gt::random_subgroup(&mut rng))
+            .collect::<Vec<_>>();
+        elements.push(Gt::identity());
+        assert!(Gt::batch_in_subgroup(&[]));
+        assert!(Gt::batch_in_subgroup(&elements));
+
+        elements[2] = Gt(Fp12::random(&mut rng));
+        assert!(!Gt::batch_in_subgroup(&elements));
+
+        // -1 is unitary but has order 2
+        elements[2] = Gt(-Fp12::ONE);
+        assert!(!Gt::batch_in_subgroup(&elements));
+    }
+
+    #[test]
+    fn test_gt_from_bytes_any() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = Gt::random_subgroup(&mut rng);
+        let uncompressed = g.to_bytes();
+        let compressed = g.to_compressed_bytes().unwrap();
+        assert_eq!(Gt::from_bytes_any(uncompressed.as_ref()).unwrap(), g);
+        assert_eq!(Gt::from_bytes_any(&compressed).unwrap(), g);
+        assert_eq!(
+            Gt::from_bytes_any(Gt::identity().to_bytes().as_ref()).unwrap(),
+            Gt::identity()
+        );
+
+        assert!(bool::from(Gt::from_bytes_any(&[0u8; 100]).is_none()));
+        assert!(bool::from(Gt::from_bytes_any(&[]).is_none()));
+        assert!(bool::from(
+            Gt::from_bytes_any(&uncompressed.as_ref()[1..]).is_none()
+        ));
+
+        // Not in the subgroup
+        let bad = Gt(Fp12::random(&mut rng)).to_bytes();
+        assert!(bool::from(Gt::from_bytes_any(bad.as_ref()).is_none()));
+    }
+
+    #[test]
+    fn test_gt_try_from_fp12() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = G1Affine::generator();
+        let h = G2Prepared::from(G2Affine::generator());
+        let gt = multi_miller_loop(&[(&g, &h)]).final_exponentiation();
+        assert_eq!(Gt::try_from_fp12(gt.0).unwrap(), gt);
+        assert_eq!(Gt::try_from_fp12(Fp12::ONE).unwrap(), Gt::identity());
+
+        for _ in 0..10 {
+            let fp12 = Fp12::random(&mut rng);
+            assert!(bool::from(Gt::try_from_fp12(fp12).is_none()));
+        }
+    }
+
     #[test]
     fn test_gt_unitary() {
         let g = G1Affine::generator();
@@ -680,6 +1681,281 @@ mod tests {
         assert_eq!(q, r);
     }
 
+    #[test]
+    fn test_is_identity() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert!(bool::from(Gt::identity().is_identity()));
+        assert!(bool::from(
+            (Gt::generator() - Gt::generator()).is_identity()
+        ));
+        assert!(bool::from((Gt::generator() * Scalar::ZERO).is_identity()));
+        assert!(!bool::from(Gt::generator().is_identity()));
+        for _ in 0..10 {
+            let a = Gt::random(&mut rng);
+            assert_eq!(
+                bool::from(a.is_identity()),
+                bool::from(a.0.ct_eq(&Gt::identity().0))
+            );
+            assert!(!bool::from(a.is_identity()));
+        }
+    }
+
+    #[test]
+    fn test_tagged_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for a in [Gt::generator(), Gt::random_subgroup(&mut rng)] {
+            let uncompressed = a.to_tagged_bytes(false);
+            assert_eq!(uncompressed.len(), 1 + Gt::BYTES);
+            assert_eq!(uncompressed[0], 0);
+            assert_eq!(Gt::from_tagged_bytes(&uncompressed).unwrap(), a);
+
+            let compressed = a.to_tagged_bytes(true);
+            assert_eq!(compressed.len(), 1 + GtCompressed::BYTES);
+            assert_eq!(compressed[0], 1);
+            assert_eq!(Gt::from_tagged_bytes(&compressed).unwrap(), a);
+
+            // Unknown tags and mismatched lengths
+            let mut bytes = compressed.clone();
+            bytes[0] = 2;
+            assert!(bool::from(Gt::from_tagged_bytes(&bytes).is_none()));
+            bytes[0] = 0;
+            assert!(bool::from(Gt::from_tagged_bytes(&bytes).is_none()));
+            let mut bytes = uncompressed.clone();
+            bytes[0] = 1;
+            assert!(bool::from(Gt::from_tagged_bytes(&bytes).is_none()));
+            assert!(bool::from(
+                Gt::from_tagged_bytes(&uncompressed[..Gt::BYTES]).is_none()
+            ));
+        }
+
+        // The identity has no compressed form
+        let identity = Gt::identity().to_tagged_bytes(true);
+        assert_eq!(identity[0], 0);
+        assert_eq!(Gt::from_tagged_bytes(&identity).unwrap(), Gt::identity());
+
+        // Not in the subgroup
+        let mut bytes = vec![0u8];
+        bytes.extend_from_slice(Gt(Fp12::random(&mut rng)).to_bytes().as_ref());
+        assert!(bool::from(Gt::from_tagged_bytes(&bytes).is_none()));
+
+        assert!(bool::from(Gt::from_tagged_bytes(&[]).is_none()));
+    }
+
+    #[test]
+    fn test_cached() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let a = Gt::random_subgroup(&mut rng);
+        let cached = GtCached::new(a);
+        assert_eq!(cached.value(), &a);
+
+        let first = cached.compressed().unwrap();
+        assert_eq!(Some(*first), a.compress());
+        let second = cached.compressed().unwrap();
+        assert!(core::ptr::eq(first, second));
+        assert_eq!(first.uncompress(), Some(a));
+
+        let cloned = cached.clone();
+        assert_eq!(cloned.compressed(), a.compress().as_ref());
+
+        // The identity has no compressed form
+        let identity = GtCached::from(Gt::identity());
+        assert_eq!(identity.compressed(), None);
+        assert_eq!(identity.compressed(), None);
+    }
+
+    #[test]
+    fn test_assert_bilinear() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        Gt::assert_bilinear(&Scalar::ZERO, &Scalar::ONE);
+        for _ in 0..3 {
+            Gt::assert_bilinear(&Scalar::random(&mut rng), &Scalar::random(&mut rng));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "pairing is not bilinear")]
+    fn test_assert_bilinear_inconsistent() {
+        let a = Scalar::from(3u64);
+        let b = Scalar::from(5u64);
+        Gt::assert_bilinear_with(&a, &b, &(a + b));
+    }
+
+    #[test]
+    fn test_double_assign() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for a in [Gt::identity(), Gt::generator(), Gt::random(&mut rng)] {
+            let mut b = a;
+            b.double_assign();
+            assert_eq!(b, a.double());
+            assert_eq!(b, a + a);
+            assert_eq!(b, a * Scalar::from(2u64));
+        }
+    }
+
+    #[test]
+    fn test_mul_outside_subgroup() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // Neither `From<Fp12>` nor `from_bytes` check the subgroup, so the
+        // arithmetic has to be correct for any `Fp12`.
+        for _ in 0..5 {
+            let x = Gt::from(Fp12::random(&mut rng));
+            assert!(!x.is_in_subgroup());
+
+            let mut doubled = x;
+            doubled.double_assign();
+            assert_eq!(doubled, x.double());
+            assert_eq!(doubled, x + x);
+            assert_eq!(x * Scalar::from(3u64), x + x + x);
+            assert_eq!(x * Scalar::from(6u64), (x + x + x).double());
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_notation() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Gt::one(), Gt::identity());
+        assert_eq!(Gt::one().0, Fp12::ONE);
+
+        let a = Gt::random(&mut rng);
+        let b = Gt::random(&mut rng);
+        assert_eq!(a.mul_group(&b), a + b);
+        assert_eq!(a.mul_group(&Gt::one()), a);
+        assert_eq!(a.inverse(), -a);
+        assert_eq!(a.mul_group(&a.inverse()), Gt::one());
+    }
+
+    #[test]
+    fn test_add_mul_semantics() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = Gt::generator();
+        let two = Scalar::from(2u64);
+        let three = Scalar::from(3u64);
+
+        // Both `Add` and `Mul<Gt>` multiply in `Fp12`
+        assert_eq!(g + g, g * g);
+        assert_eq!(g + g, Gt::product(&g, &g));
+        assert_eq!(g + g, g.double());
+        assert_eq!(g + g, Gt(g.0.square()));
+        // `Mul<Scalar>` is exponentiation
+        assert_eq!(g * two, g + g);
+        assert_eq!(g * three, g * g * g);
+        assert_eq!(g * two + g * three, g * Scalar::from(5u64));
+        assert_eq!(g - g, Gt::identity());
+
+        let elements: Vec<Gt> = (0..5).map(|_| Gt::random(&mut rng)).collect();
+        let sum: Gt = elements.iter().sum();
+        let product: Gt = elements.iter().product();
+        assert_eq!(sum, product);
+        assert_eq!(Gt::product_of(&elements), sum);
+        assert_eq!(Gt::product_of(elements.clone()), sum);
+        assert_eq!(Gt::product_of(Vec::<Gt>::new()), Gt::identity());
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for a in [Gt::identity(), Gt::generator(), Gt::random(&mut rng)] {
+            assert_eq!(a.conjugate(), -a);
+            assert_eq!(a.conjugate(), a.invert().unwrap());
+            assert_eq!(a.conjugate().conjugate(), a);
+            assert_eq!(a + a.conjugate(), Gt::identity());
+
+            let mut b = a;
+            b.conjugate_assign();
+            assert_eq!(b, -a);
+        }
+    }
+
+    #[test]
+    fn test_bytes_le() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for a in [Gt::identity(), Gt::generator(), Gt::random(&mut rng)] {
+            let le = a.to_bytes_le();
+            let be = a.to_bytes();
+            for (l, b) in le.chunks_exact(48).zip(be.as_ref().chunks_exact(48)) {
+                assert!(l.iter().eq(b.iter().rev()));
+            }
+
+            assert_eq!(Gt::from_bytes_le(&le).unwrap(), a);
+            assert_eq!(Gt::from_bytes(&be).unwrap(), a);
+        }
+
+        // A non-canonical coefficient
+        let mut le = Gt::generator().to_bytes_le();
+        le[48 * 5..48 * 6].fill(0xff);
+        assert!(bool::from(Gt::from_bytes_le(&le).is_none()));
+    }
+
+    #[test]
+    fn test_sum_of_pairings() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut g1 = Vec::new();
+        let mut g2 = Vec::new();
+        for _ in 0..4 {
+            g1.push(G1Projective::random(&mut rng).to_affine());
+            g2.push(G2Projective::random(&mut rng).to_affine());
+        }
+        g1.push(G1Affine::identity());
+        g2.push(G2Projective::random(&mut rng).to_affine());
+        g1.push(G1Projective::random(&mut rng).to_affine());
+        g2.push(G2Affine::identity());
+
+        let terms: Vec<(&G1Affine, &G2Affine)> = g1.iter().zip(g2.iter()).collect();
+        let expected = terms
+            .iter()
+            .fold(Gt::identity(), |acc, (p, q)| acc + pairing(p, q));
+
+        assert_eq!(Gt::sum_of_pairings(&terms), expected);
+        assert_eq!(Gt::sum_of_pairings(&terms[..1]), pairing(&g1[0], &g2[0]));
+        assert_eq!(Gt::sum_of_pairings(&terms[4..]), Gt::identity());
+        assert_eq!(Gt::sum_of_pairings(&[]), Gt::identity());
+    }
+
     #[test]
     fn test_multi_miller_loop() {
         let a1 = G1Affine::generator();
@@ -809,6 +2085,294 @@ mod tests {
         assert!(a.is_in_subgroup());
     }
 
+    #[test]
+    fn test_normalize() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let a = Gt::random(&mut rng);
+            let b = Gt::random(&mut rng);
+
+            let mut c = a + b - b;
+            assert_eq!(c, a);
+            c.normalize();
+            assert_eq!(c, a);
+
+            // Add the modulus to one coefficient, which represents the same value
+            let mut unreduced = a;
+            let mut carry = 0u128;
+            for (limb, m) in (unreduced.0).0.fp6[0].fp2[0].fp[0]
+                .l
+                .iter_mut()
+                .zip(crate::fp::MODULUS.iter())
+            {
+                let sum = *limb as u128 + *m as u128 + carry;
+                *limb = sum as u64;
+                carry = sum >> 64;
+            }
+            assert_eq!(carry, 0);
+            assert_ne!(unreduced, a);
+
+            unreduced.normalize();
+            assert_eq!(unreduced, a);
+        }
+    }
+
+    #[test]
+    fn test_compressed_encoding() {
+        fn round_trip<G: GroupEncoding + PartialEq + fmt::Debug>(g: G) -> usize {
+            let bytes = g.to_bytes();
+            assert_eq!(G::from_bytes(&bytes).unwrap(), g);
+            assert_eq!(G::from_bytes_unchecked(&bytes).unwrap(), g);
+            bytes.as_ref().len()
+        }
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for g in [Gt::identity(), Gt::generator(), Gt::random(&mut rng)] {
+            assert_eq!(round_trip(GtCompressedEncoding(g)), GtCompressed::BYTES);
+            assert_eq!(round_trip(g), Gt::BYTES);
+            assert_eq!(
+                GtCompressedEncoding(g).to_bytes().0.to_vec(),
+                g.to_compressed_bytes()
+                    .map_or(vec![0u8; GtCompressed::BYTES], |b| b.to_vec())
+            );
+        }
+
+        // Not in the subgroup
+        let mut bytes = GtCompressedEncoding(Gt::generator()).to_bytes();
+        bytes.0[0] ^= 1;
+        assert!(bool::from(
+            GtCompressedEncoding::from_bytes(&bytes).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_compressed_eq() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // The identity can't be compressed
+        assert!(Gt::identity().compress().is_none());
+
+        for _ in 0..5 {
+            let a = Gt::random_subgroup(&mut rng);
+            let s = Scalar::random(&mut rng);
+            // The same element computed two different ways
+            let lhs = (a * s + a).compress().unwrap();
+            let rhs = (a * (s + Scalar::ONE)).compress().unwrap();
+            assert_eq!(lhs, rhs);
+            assert!(bool::from(lhs.ct_eq(&rhs)));
+            assert_eq!(lhs.uncompress().unwrap(), a * (s + Scalar::ONE));
+
+            let other = (a * s).compress().unwrap();
+            assert_ne!(lhs, other);
+            assert!(!bool::from(lhs.ct_eq(&other)));
+        }
+    }
+
+    #[test]
+    fn test_compressed_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut elements = vec![Gt::generator()];
+        elements.extend((0..5).map(|_| Gt::random_subgroup(&mut rng)));
+        for gt in elements {
+            let bytes = gt.to_compressed_bytes().unwrap();
+            assert_eq!(Gt::from_compressed_bytes(&bytes), Some(gt));
+
+            let mut written = Vec::new();
+            gt.write_compressed(&mut written).unwrap();
+            assert_eq!(written, bytes.to_vec());
+        }
+
+        // Canonical coefficients that do not decompress into the subgroup
+        let mut bytes = Gt::generator().to_compressed_bytes().unwrap();
+        bytes[0] ^= 1;
+        assert_eq!(Gt::from_compressed_bytes(&bytes), None);
+
+        // Non-canonical coefficients
+        assert_eq!(
+            Gt::from_compressed_bytes(&[0xff; GtCompressed::BYTES]),
+            None
+        );
+
+        // Elements with a zero `c1` coefficient have no torus compression
+        assert_eq!(Gt::identity().to_compressed_bytes(), None);
+    }
+
+    #[test]
+    fn test_batch_uncompress() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut elements = vec![Gt::generator()];
+        elements.extend((0..5).map(|_| Gt::random_subgroup(&mut rng)));
+        let mut compressed: Vec<GtCompressed> =
+            elements.iter().map(|e| e.compress().unwrap()).collect();
+
+        let uncompressed = Gt::batch_uncompress(&compressed).unwrap();
+        assert_eq!(uncompressed, elements);
+        for (c, e) in compressed.iter().zip(uncompressed.iter()) {
+            assert_eq!(c.uncompress().as_ref(), Some(e));
+        }
+        assert_eq!(Gt::batch_uncompress(&[]), Some(vec![]));
+
+        let mut bytes = compressed[3].to_bytes_le();
+        bytes[0] ^= 1;
+        compressed[3] = GtCompressed::from_bytes_le(&bytes).unwrap();
+        assert_eq!(compressed[3].uncompress(), None);
+        assert_eq!(Gt::batch_uncompress(&compressed), None);
+    }
+
+    #[test]
+    fn test_random_subgroup() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let samples = (0..10)
+            .map(|_| Gt::random_subgroup(&mut rng))
+            .collect::<Vec<_>>();
+        for (i, a) in samples.iter().enumerate() {
+            assert!(a.is_in_subgroup());
+            assert!(!bool::from(a.is_identity()));
+            assert!(samples[i + 1..].iter().all(|b| a != b));
+        }
+
+        let a = Gt::random_subgroup(XorShiftRng::seed_from_u64(1));
+        let b = Gt::random_subgroup(XorShiftRng::seed_from_u64(2));
+        assert_ne!(a, b);
+        assert_eq!(a, Gt::random_subgroup(XorShiftRng::seed_from_u64(1)));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut gt = Gt::random(&mut rng);
+        let mut compressed = gt.compress().unwrap();
+
+        gt.zeroize();
+        assert!(gt.to_bytes().as_ref().iter().all(|&b| b == 0));
+        assert!(bool::from(gt.0.is_zero()));
+
+        compressed.zeroize();
+        assert!(compressed.to_bytes_le().iter().all(|&b| b == 0));
+        assert_eq!(compressed, GtCompressed::default());
+
+        let gt = zeroize::Zeroizing::new(Gt::random(&mut rng));
+        let compressed = zeroize::Zeroizing::new(gt.compress().unwrap());
+        assert_eq!(compressed.uncompress().unwrap(), *gt);
+    }
+
+    #[test]
+    fn test_mul_naf() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..5 {
+            let g = Gt::random(&mut rng);
+            let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+            scalars.extend((0..5).map(|_| Scalar::random(&mut rng)));
+            for s in scalars.iter() {
+                let expected = g * s;
+                for window in 2..=6 {
+                    assert_eq!(g.mul_naf(s, window), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_u64() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = Gt::random(&mut rng);
+        assert_eq!(g.pow_u64(0), Gt::identity());
+        assert_eq!(g.pow_u64(1), g);
+        for n in (0..64).chain([u32::MAX as u64, u64::MAX]) {
+            assert_eq!(g.pow_u64(n), g * Scalar::from(n));
+        }
+    }
+
+    #[test]
+    fn test_batch_to_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let elements = (0..10).map(|_| Gt::random(&mut rng)).collect::<Vec<_>>();
+        let mut out = vec![0u8; Gt::BYTES * elements.len()];
+        Gt::batch_to_bytes(&elements, &mut out);
+        for (e, chunk) in elements.iter().zip(out.chunks_exact(Gt::BYTES)) {
+            assert_eq!(chunk, e.to_bytes().as_ref());
+        }
+
+        Gt::batch_to_bytes(&[], &mut []);
+    }
+
+    #[test]
+    fn test_generator_consts() {
+        assert_eq!(Gt::GENERATOR, Gt::generator());
+        assert_eq!(
+            Gt::GENERATOR,
+            pairing(&G1Affine::generator(), &G2Affine::generator())
+        );
+        assert_eq!(Gt::generator().compress(), Some(GtCompressed::GENERATOR));
+        assert_eq!(
+            GtCompressed::GENERATOR.uncompress().unwrap(),
+            Gt::generator()
+        );
+    }
+
+    #[test]
+    fn test_cyclotomic_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let x = -Scalar::from(BLS_X);
+
+        for _ in 0..10 {
+            let p = G1Projective::random(&mut rng).to_affine();
+            let q = G2Projective::random(&mut rng).to_affine();
+            let a = pairing(&p, &q);
+
+            assert_eq!(a.cyclotomic_exp_by_x(), a * x);
+            assert_eq!(a.cyclotomic_exp(&[BLS_X]), -(a * x));
+
+            let s = Scalar::random(&mut rng);
+            assert_eq!(a.cyclotomic_exp(&s.to_raw()), a * s);
+            assert_eq!(a.cyclotomic_exp(&[0]), Gt::identity());
+            assert_eq!(a.cyclotomic_exp(&[]), Gt::identity());
+            assert_eq!(a.cyclotomic_exp(&[1, 0]), a);
+        }
+    }
+
     #[test]
     fn compatibility() {
         let gt1 = pairing(&G1Affine::generator(), &G2Affine::generator());
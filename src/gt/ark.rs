@@ -0,0 +1,56 @@
+use ark_bls12_381::{Fq, Fq12, Fq2, Fq6};
+use ark_ff::{BigInteger384, PrimeField as ArkPrimeField};
+use blst::blst_uint64_from_fp;
+
+use super::Gt;
+use crate::{fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6};
+
+fn fp_to_fq(fp: Fp) -> Fq {
+    let mut limbs = [0u64; 6];
+    unsafe { blst_uint64_from_fp(limbs.as_mut_ptr(), &fp.0) };
+    Fq::from_bigint(BigInteger384::new(limbs)).expect("Fp is always canonical")
+}
+
+fn fq_to_fp(fq: Fq) -> Fp {
+    Fp::from_raw(&fq.into_bigint().0).expect("Fq is always canonical")
+}
+
+fn fp2_to_fq2(fp2: Fp2) -> Fq2 {
+    Fq2::new(fp_to_fq(fp2.c0()), fp_to_fq(fp2.c1()))
+}
+
+fn fq2_to_fp2(fq2: Fq2) -> Fp2 {
+    Fp2::new(fq_to_fp(fq2.c0), fq_to_fp(fq2.c1))
+}
+
+fn fp6_to_fq6(fp6: Fp6) -> Fq6 {
+    Fq6::new(
+        fp2_to_fq2(fp6.c0()),
+        fp2_to_fq2(fp6.c1()),
+        fp2_to_fq2(fp6.c2()),
+    )
+}
+
+fn fq6_to_fp6(fq6: Fq6) -> Fp6 {
+    Fp6::new(fq2_to_fp2(fq6.c0), fq2_to_fp2(fq6.c1), fq2_to_fp2(fq6.c2))
+}
+
+impl From<Gt> for Fq12 {
+    fn from(gt: Gt) -> Self {
+        Fq12::new(fp6_to_fq6(gt.0.c0()), fp6_to_fq6(gt.0.c1()))
+    }
+}
+
+impl TryFrom<Fq12> for Gt {
+    type Error = String;
+
+    /// Converts an arkworks `Fq12`, failing if it is not an element of $\mathbb{G}_T$.
+    fn try_from(fq12: Fq12) -> Result<Self, Self::Error> {
+        let gt = Gt(Fp12::new(fq6_to_fp6(fq12.c0), fq6_to_fp6(fq12.c1)));
+        if gt.is_in_subgroup() {
+            Ok(gt)
+        } else {
+            Err("Invalid Fq12 for Gt, not in the target group".to_string())
+        }
+    }
+}
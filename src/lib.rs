@@ -24,19 +24,24 @@ mod scalar;
 mod traits;
 mod util;
 
-pub use g1::{G1Affine, G1Compressed, G1Projective, G1Uncompressed};
+pub use g1::{G1Affine, G1Compressed, G1Prepared, G1Projective, G1Uncompressed};
 pub use g2::{G2Affine, G2Compressed, G2Prepared, G2Projective, G2Uncompressed};
-pub use gt::Gt;
+pub use gt::{Gt, GtCached, GtCompressed, GtCompressedEncoding, GtGeneratorTable, GtPrecompute};
 pub use pairing::*;
-pub use scalar::Scalar;
+#[cfg(feature = "lossy-conversions")]
+pub use scalar::ConversionError;
+pub use scalar::{HexError, LengthError, ParseScalarError, Scalar, ScalarError, ScalarPowTable};
 pub use traits::Compress;
 
 #[cfg(feature = "serde")]
 mod serde_impl;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 mod tests;
 
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 // export for benchmarking only
 #[cfg(feature = "__private_bench")]
 pub use crate::{fp::Fp, fp12::Fp12, fp2::Fp2};
@@ -50,6 +55,9 @@ pub use ff;
 pub use group;
 pub use pairing_lib;
 
+/// The absolute value of the BLS parameter `x = -0xd201000000010000`.
+pub(crate) const BLS_X: u64 = 0xd201_0000_0001_0000;
+
 /// Bls12-381 engine
 #[derive(Debug, Copy, Clone)]
 pub struct Bls12;
@@ -373,6 +373,17 @@ macro_rules! impl_pippenger_sum_of_products {
 
 macro_rules! impl_from_bytes {
     ($name:ident, $tobytesfunc:expr, $frombytesfunc:expr) => {
+        impl_from_bytes!(
+            $name,
+            $tobytesfunc,
+            |arr: &[u8]| -> Result<$name, String> {
+                Option::<$name>::from($frombytesfunc(arr)?)
+                    .ok_or_else(|| format!("Invalid bytes for {}", stringify!($name)))
+            },
+            String
+        );
+    };
+    ($name:ident, $tobytesfunc:expr, $frombytesfunc:expr, $error:ty) => {
         impl From<$name> for Vec<u8> {
             fn from(value: $name) -> Self {
                 Self::from(&value)
@@ -386,7 +397,7 @@ macro_rules! impl_from_bytes {
         }
 
         impl TryFrom<Vec<u8>> for $name {
-            type Error = String;
+            type Error = $error;
 
             fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
                 Self::try_from(value.as_slice())
@@ -394,7 +405,7 @@ macro_rules! impl_from_bytes {
         }
 
         impl TryFrom<&Vec<u8>> for $name {
-            type Error = String;
+            type Error = $error;
 
             fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
                 Self::try_from(value.as_slice())
@@ -402,7 +413,7 @@ macro_rules! impl_from_bytes {
         }
 
         impl TryFrom<Box<[u8]>> for $name {
-            type Error = String;
+            type Error = $error;
 
             fn try_from(value: Box<[u8]>) -> Result<Self, Self::Error> {
                 Self::try_from(value.as_ref())
@@ -410,11 +421,10 @@ macro_rules! impl_from_bytes {
         }
 
         impl TryFrom<&[u8]> for $name {
-            type Error = String;
+            type Error = $error;
 
             fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-                Option::<$name>::from($frombytesfunc(value)?)
-                    .ok_or_else(|| format!("Invalid bytes for {}", stringify!($name)))
+                $frombytesfunc(value)
             }
         }
     };
@@ -1,6 +1,10 @@
-use crate::{fp12::Fp12, Bls12, G1Affine, G2Affine, G2Prepared, Gt};
+use crate::{
+    fp12::Fp12, Bls12, G1Affine, G1Prepared, G1Projective, G2Affine, G2Prepared, Gt, Scalar,
+};
 use core::ops::{Add, AddAssign};
 use ff::Field;
+use group::{prime::PrimeCurveAffine, Curve, Group};
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable};
 
 use blst::*;
@@ -210,3 +214,345 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopResult
 
     Bls12::multi_miller_loop(terms)
 }
+
+/// Computes $$\sum_{i=1}^n \textbf{ML}(a_i, b_i)$$ given a series of terms
+/// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n)$$ where the `G1` side is prepared.
+///
+/// This gives the same result as [`multi_miller_loop`] and is no faster, see
+/// [`G1Prepared`].
+pub fn multi_miller_loop_g1_prepared(terms: &[(&G1Prepared, &G2Affine)]) -> MillerLoopResult {
+    let mut res = Fp12::ONE.0;
+
+    for (p, q) in terms {
+        // Define pairing with zero as one, matching what `pairing` does.
+        if (p.is_identity() | q.is_identity()).into() {
+            continue;
+        }
+        let mut tmp = blst_fp12::default();
+        unsafe {
+            blst_miller_loop(&mut tmp, &q.0, &p.point.0);
+            blst_fp12_mul(&mut res, &res, &tmp);
+        }
+    }
+
+    MillerLoopResult(Fp12(res))
+}
+
+/// Returns true if $$\prod_{i=1}^n e(a_i, b_i)$$ is the identity in $\mathbb{G}_T$
+/// given a series of terms $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
+///
+/// See [`Gt::sum_of_pairings`].
+pub fn multi_pairing_is_identity(terms: &[(&G1Affine, &G2Affine)]) -> bool {
+    Gt::sum_of_pairings(terms).is_identity().into()
+}
+
+/// Accumulates Miller loops of terms as they arrive, so that
+/// $$\sum_{i=1}^n e(a_i, b_i)$$ can be computed without buffering all points
+/// and with only one final exponentiation.
+#[derive(Copy, Clone, Debug)]
+pub struct MillerAccumulator(blst_fp12);
+
+impl Default for MillerAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MillerAccumulator {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self(Fp12::ONE.0)
+    }
+
+    /// Multiply the Miller loop of `(g1, g2)` into the running product.
+    pub fn add_term(&mut self, g1: &G1Affine, g2: &G2Affine) {
+        // Pairing with zero is one, matching what `pairing` does.
+        if (g1.is_identity() | g2.is_identity()).into() {
+            return;
+        }
+
+        let mut tmp = blst_fp12::default();
+        unsafe {
+            blst_miller_loop(&mut tmp, &g2.0, &g1.0);
+            blst_fp12_mul(&mut self.0, &self.0, &tmp);
+        }
+    }
+
+    /// Run the final exponentiation over the accumulated terms.
+    pub fn finalize(self) -> Gt {
+        let mut out = blst_fp12::default();
+        unsafe { blst_final_exp(&mut out, &self.0) };
+        Gt(Fp12(out))
+    }
+}
+
+/// Returns true if $$\prod e(a_i, b_i) = \prod e(c_j, d_j)$$ given the terms
+/// $$(a_i, b_i)$$ of `lhs` and $$(c_j, d_j)$$ of `rhs`.
+///
+/// The `G1` points of `rhs` are negated so that a single multi-Miller loop and
+/// final exponentiation is done over both sides.
+pub fn pairing_eq(lhs: &[(&G1Affine, &G2Affine)], rhs: &[(&G1Affine, &G2Affine)]) -> bool {
+    let neg_rhs: Vec<G1Affine> = rhs.iter().map(|(p, _)| -**p).collect();
+    let terms: Vec<(&G1Affine, &G2Affine)> = lhs
+        .iter()
+        .copied()
+        .chain(neg_rhs.iter().zip(rhs.iter()).map(|(p, (_, q))| (p, *q)))
+        .collect();
+
+    multi_pairing_is_identity(&terms)
+}
+
+/// Verifies an aggregate signature `sig` over the messages hashed to $$H_i$$ under
+/// the public keys $$pk_i$$, by checking
+/// $$e(\sigma, g_2) = \prod e(H_i, pk_i)$$ with one multi-Miller loop and final
+/// exponentiation.
+///
+/// Returns false if `msgs_and_keys` is empty.
+pub fn verify_aggregate(
+    sig: &G1Affine,
+    g2: &G2Affine,
+    msgs_and_keys: &[(&G1Affine, &G2Affine)],
+) -> bool {
+    !msgs_and_keys.is_empty() && pairing_eq(msgs_and_keys, &[(sig, g2)])
+}
+
+/// A pairing equation $$e(a, b) = e(c, d)$$ to be checked by [`batch_pairing_verify`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PairingEq {
+    /// The `G1` point on the left hand side.
+    pub a: G1Affine,
+    /// The `G2` point on the left hand side.
+    pub b: G2Affine,
+    /// The `G1` point on the right hand side.
+    pub c: G1Affine,
+    /// The `G2` point on the right hand side.
+    pub d: G2Affine,
+}
+
+impl PairingEq {
+    /// Create the equation $$e(a, b) = e(c, d)$$.
+    pub fn new(a: G1Affine, b: G2Affine, c: G1Affine, d: G2Affine) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+/// Returns true if every equation $$e(a_i, b_i) = e(c_i, d_i)$$ holds.
+///
+/// Each equation is weighted by a random scalar $$r_i$$ and the check
+/// $$\prod_{i=1}^n e(r_i a_i, b_i) \cdot e(-r_i c_i, d_i) = 1$$
+/// is done with a single multi-Miller loop and final exponentiation.
+/// If any equation does not hold the check fails with overwhelming probability.
+pub fn batch_pairing_verify(eqs: &[PairingEq], mut rng: impl RngCore) -> bool {
+    use pairing_lib::MillerLoopResult as _;
+
+    let mut g1 = Vec::with_capacity(eqs.len() * 2);
+    let mut g2 = Vec::with_capacity(eqs.len() * 2);
+    for eq in eqs {
+        let r = Scalar::random(&mut rng);
+        g1.push(G1Projective::from(eq.a) * r);
+        g1.push(G1Projective::from(-eq.c) * r);
+        g2.push(G2Prepared::from(eq.b));
+        g2.push(G2Prepared::from(eq.d));
+    }
+
+    let mut g1_affine = vec![G1Affine::default(); g1.len()];
+    G1Projective::batch_normalize(&g1, &mut g1_affine);
+
+    let terms: Vec<(&G1Affine, &G2Prepared)> = g1_affine.iter().zip(g2.iter()).collect();
+
+    multi_miller_loop(&terms)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_multi_pairing_is_identity() {
+        let g = G1Affine::generator();
+        let h = G2Affine::generator();
+        let neg_g = -g;
+
+        assert!(multi_pairing_is_identity(&[]));
+        assert!(multi_pairing_is_identity(&[(&g, &h), (&neg_g, &h)]));
+        assert!(!multi_pairing_is_identity(&[(&g, &h), (&g, &h)]));
+
+        let a = Scalar::from(5u64);
+        let ag = G1Affine::from(g * a);
+        let ah = G2Affine::from(h * a);
+        let neg_ag = -ag;
+        assert!(multi_pairing_is_identity(&[(&g, &ah), (&neg_ag, &h)]));
+        assert!(!multi_pairing_is_identity(&[(&g, &ah), (&ag, &h)]));
+
+        let zero = G1Affine::identity();
+        assert!(multi_pairing_is_identity(&[(&zero, &h)]));
+    }
+
+    #[test]
+    fn test_multi_miller_loop_g1_prepared() {
+        use pairing_lib::MillerLoopResult as _;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut g1: Vec<G1Affine> = (0..3)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let mut g2: Vec<G2Affine> = (0..3)
+            .map(|_| crate::G2Projective::random(&mut rng).to_affine())
+            .collect();
+        g1.push(G1Affine::identity());
+        g2.push(G2Affine::generator());
+        g1.push(G1Affine::generator());
+        g2.push(G2Affine::identity());
+
+        let g1_prepared: Vec<G1Prepared> = g1.iter().map(|p| G1Prepared::from(*p)).collect();
+        let g2_prepared: Vec<G2Prepared> = g2.iter().map(|q| G2Prepared::from(*q)).collect();
+
+        let expected = multi_miller_loop(&g1.iter().zip(g2_prepared.iter()).collect::<Vec<_>>());
+        let actual =
+            multi_miller_loop_g1_prepared(&g1_prepared.iter().zip(g2.iter()).collect::<Vec<_>>());
+        assert_eq!(actual, expected);
+
+        let terms: Vec<(&G1Affine, &G2Affine)> = g1.iter().zip(g2.iter()).collect();
+        assert_eq!(actual.final_exponentiation(), Gt::sum_of_pairings(&terms));
+        assert_eq!(
+            multi_miller_loop_g1_prepared(&[]),
+            MillerLoopResult::default()
+        );
+    }
+
+    #[test]
+    fn test_miller_accumulator() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g1: Vec<G1Affine> = (0..3)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let g2: Vec<G2Affine> = (0..3)
+            .map(|_| crate::G2Projective::random(&mut rng).to_affine())
+            .collect();
+        let terms: Vec<(&G1Affine, &G2Affine)> = g1.iter().zip(g2.iter()).collect();
+
+        let mut acc = MillerAccumulator::new();
+        for (p, q) in &terms {
+            acc.add_term(p, q);
+        }
+        assert_eq!(acc.finalize(), Gt::sum_of_pairings(&terms));
+
+        let mut acc = MillerAccumulator::default();
+        acc.add_term(&G1Affine::identity(), &g2[0]);
+        acc.add_term(&g1[1], &G2Affine::identity());
+        assert_eq!(acc.finalize(), Gt::identity());
+        assert_eq!(MillerAccumulator::new().finalize(), Gt::identity());
+    }
+
+    #[test]
+    fn test_pairing_eq() {
+        let g = G1Affine::generator();
+        let h = G2Affine::generator();
+
+        let a = Scalar::from(6u64);
+        let b = Scalar::from(7u64);
+        let c = Scalar::from(10u64);
+        let ag = G1Affine::from(g * a);
+        let bh = G2Affine::from(h * b);
+        let cg = G1Affine::from(g * c);
+        let rest = G1Affine::from(g * (a * b - c));
+
+        // e(6g, 7h) = e(10g, h) * e(32g, h)
+        assert!(pairing_eq(&[(&ag, &bh)], &[(&cg, &h), (&rest, &h)]));
+        assert!(pairing_eq(&[(&cg, &h), (&rest, &h)], &[(&ag, &bh)]));
+        assert!(!pairing_eq(&[(&ag, &bh)], &[(&cg, &h)]));
+        assert!(!pairing_eq(&[(&ag, &bh)], &[(&cg, &h), (&rest, &bh)]));
+
+        assert!(pairing_eq(&[], &[]));
+        assert!(pairing_eq(&[(&ag, &bh)], &[(&ag, &bh)]));
+    }
+
+    #[test]
+    fn test_verify_aggregate() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let h = G2Affine::generator();
+        let sks = (0..4).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let pks = sks
+            .iter()
+            .map(|sk| G2Affine::from(h * sk))
+            .collect::<Vec<_>>();
+        let msgs = (0..4)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect::<Vec<_>>();
+        let sig = msgs
+            .iter()
+            .zip(sks.iter())
+            .map(|(m, sk)| m * sk)
+            .sum::<G1Projective>()
+            .to_affine();
+        let terms = msgs.iter().zip(pks.iter()).collect::<Vec<_>>();
+
+        assert!(verify_aggregate(&sig, &h, &terms));
+
+        // Tampered signature, message or key
+        let bad_sig = (sig + G1Projective::generator()).to_affine();
+        assert!(!verify_aggregate(&bad_sig, &h, &terms));
+        let mut bad_terms = terms.clone();
+        bad_terms[1].0 = &msgs[2];
+        assert!(!verify_aggregate(&sig, &h, &bad_terms));
+        let mut bad_terms = terms.clone();
+        bad_terms[0].1 = &pks[1];
+        assert!(!verify_aggregate(&sig, &h, &bad_terms));
+        assert!(!verify_aggregate(&sig, &h, &terms[..3]));
+
+        assert!(!verify_aggregate(&G1Affine::identity(), &h, &[]));
+    }
+
+    #[test]
+    fn test_batch_pairing_verify() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let g = G1Affine::generator();
+        let h = G2Affine::generator();
+
+        let mut eqs = Vec::new();
+        for _ in 0..4 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            // e(a * g, b * h) = e(ab * g, h)
+            eqs.push(PairingEq::new(
+                G1Affine::from(g * a),
+                G2Affine::from(h * b),
+                G1Affine::from(g * (a * b)),
+                h,
+            ));
+        }
+
+        assert!(batch_pairing_verify(&[], &mut rng));
+        assert!(batch_pairing_verify(&eqs, &mut rng));
+
+        let mut bad = eqs.clone();
+        bad[2].c = -bad[2].c;
+        assert!(!batch_pairing_verify(&bad, &mut rng));
+
+        let mut bad = eqs;
+        bad[0].d = G2Affine::from(h * Scalar::from(2u64));
+        assert!(!batch_pairing_verify(&bad, &mut rng));
+    }
+}
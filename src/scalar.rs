@@ -3,6 +3,8 @@
 
 #[cfg(feature = "ark")]
 mod ark;
+#[cfg(feature = "num-bigint")]
+mod biguint;
 
 use core::{
     borrow::Borrow,
@@ -11,10 +13,11 @@ use core::{
     fmt::{self, LowerHex, UpperHex},
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 use std::hash::Hash;
 
-use crate::{util, Bls12381G1};
+use crate::{util, Bls12381G1, BLS_X};
 use blst::*;
 use elliptic_curve::bigint::{ArrayEncoding, Encoding, U256, U384, U512};
 use elliptic_curve::consts::{U32, U48, U64};
@@ -22,7 +25,7 @@ use elliptic_curve::generic_array::GenericArray;
 use elliptic_curve::ops::Reduce;
 use elliptic_curve::scalar::{FromUintUnchecked, IsHigh};
 use elliptic_curve::ScalarPrimitive;
-use ff::{Field, FieldBits, PrimeField, PrimeFieldBits};
+use ff::{Field, FieldBits, FromUniformBytes, PrimeField, PrimeFieldBits};
 use rand_core::RngCore;
 use std::ops::{Div, DivAssign};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
@@ -138,8 +141,46 @@ const R3: Scalar = Scalar(blst_fr {
     ],
 });
 
+/// R^4 = 2^1024 mod q
+const R4: Scalar = Scalar(blst_fr {
+    l: [
+        0x1b9c_9191_d083_1ad3,
+        0x08fd_47d3_6f83_0a6c,
+        0xf948_ab00_b364_c750,
+        0x08a6_3526_916f_202c,
+    ],
+});
+
 pub const S: u32 = 32;
 
+/// floor((2^128 - 1) / |x|) - 2^64, the reciprocal of the BLS parameter used by
+/// [`div_rem_bls_x`].
+const BLS_X_RECIP: u64 = (u128::MAX / BLS_X as u128 - (1u128 << 64)) as u64;
+
+/// Divides `hi * 2^64 + lo` by `|x|` in constant time, returning the quotient and
+/// remainder. Requires `hi < |x|`.
+///
+/// This is the 2-by-1 division of Möller and Granlund, which multiplies by the
+/// precomputed [`BLS_X_RECIP`] and then applies two masked corrections.
+fn div_rem_bls_x(hi: u64, lo: u64) -> (u64, u64) {
+    let p = (BLS_X_RECIP as u128 * hi as u128).wrapping_add(((hi as u128) << 64) | lo as u128);
+    let mut q = ((p >> 64) as u64).wrapping_add(1);
+    let mut r = lo.wrapping_sub(q.wrapping_mul(BLS_X));
+
+    // r > p mod 2^64 means the estimate was one too large
+    let too_large = Choice::from((p as u64).overflowing_sub(r).1 as u8);
+    q.conditional_assign(&q.wrapping_sub(1), too_large);
+    r.conditional_assign(&r.wrapping_add(BLS_X), too_large);
+
+    // the estimate can also be one too small, leaving r >= |x|
+    let (diff, borrow) = r.overflowing_sub(BLS_X);
+    let too_small = !Choice::from(borrow as u8);
+    q.conditional_assign(&q.wrapping_add(1), too_small);
+    r.conditional_assign(&diff, too_small);
+
+    (q, r)
+}
+
 impl fmt::Debug for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let be_bytes = self.to_be_bytes();
@@ -152,12 +193,50 @@ impl fmt::Debug for Scalar {
     }
 }
 
+/// This prints the same `Scalar(0x…)` form as `Debug`. Use
+/// [`Scalar::to_decimal_string`] and [`FromStr`] for a canonical string
+/// round-trip.
 impl fmt::Display for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+/// Parses a canonical decimal string, or a hex string prefixed with `0x`.
+/// The integer must be less than the modulus.
+impl FromStr for Scalar {
+    type Err = ParseScalarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix, offset) = match s.strip_prefix("0x") {
+            Some(hex) => (hex, 16, 2),
+            None => (s, 10, 0),
+        };
+        if digits.is_empty() {
+            return Err(ParseScalarError::Empty);
+        }
+
+        let mut limbs = [0u64; 4];
+        let mut overflow = false;
+        for (i, c) in digits.bytes().enumerate() {
+            let digit = (c as char)
+                .to_digit(radix)
+                .ok_or(ParseScalarError::InvalidDigit { index: offset + i })?;
+            let mut carry = digit as u128;
+            for limb in limbs.iter_mut() {
+                let t = (*limb as u128) * (radix as u128) + carry;
+                *limb = t as u64;
+                carry = t >> 64;
+            }
+            overflow |= carry != 0;
+        }
+        if overflow {
+            return Err(ParseScalarError::NotInField);
+        }
+        Option::from(Self::from_raw(limbs)).ok_or(ParseScalarError::NotInField)
+    }
+}
+
 impl Ord for Scalar {
     #[allow(clippy::comparison_chain)]
     fn cmp(&self, other: &Scalar) -> cmp::Ordering {
@@ -296,6 +375,133 @@ impl fmt::Display for NotInFieldError {
 
 impl std::error::Error for NotInFieldError {}
 
+/// The error returned when a `Scalar` cannot be parsed from a byte slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarError {
+    /// The bytes encode an integer that is not less than the modulus.
+    NotCanonical,
+    /// The slice does not contain exactly `Scalar::BYTES` bytes.
+    WrongLength { expected: usize, found: usize },
+}
+
+impl fmt::Display for ScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotCanonical => write!(f, "Invalid bytes for Scalar, not canonical"),
+            Self::WrongLength { expected, found } => write!(
+                f,
+                "Invalid number of bytes for Scalar, expected {}, found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScalarError {}
+
+/// The error returned when an input is not a whole number of fixed-size chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    /// The size of each chunk.
+    pub chunk_size: usize,
+    /// The length of the input.
+    pub found: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid input length, expected a multiple of {}, found {}",
+            self.chunk_size, self.found
+        )
+    }
+}
+
+impl std::error::Error for LengthError {}
+
+/// The error returned when a `Scalar` cannot be parsed from a hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The string does not contain exactly `2 * Scalar::BYTES` hex characters.
+    InvalidLength { expected: usize, found: usize },
+    /// The string contains a character that is not a hex digit at byte `index`,
+    /// counting any `0x` prefix.
+    InvalidCharacter { index: usize },
+    /// The hex encodes an integer that is not less than the modulus.
+    NotInField,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, found } => write!(
+                f,
+                "Invalid number of hex characters for Scalar, expected {}, found {}",
+                expected, found
+            ),
+            Self::InvalidCharacter { index } => {
+                write!(f, "Invalid hex character at index {}", index)
+            }
+            Self::NotInField => write!(f, "Invalid hex for Scalar, not in field"),
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+/// The error returned when a `Scalar` cannot be parsed from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseScalarError {
+    /// The string contains no digits.
+    Empty,
+    /// The string contains a character that is not a digit in its radix.
+    InvalidDigit { index: usize },
+    /// The string encodes an integer that is not less than the modulus.
+    NotInField,
+}
+
+impl fmt::Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Invalid string for Scalar, no digits"),
+            Self::InvalidDigit { index } => write!(f, "Invalid digit at index {}", index),
+            Self::NotInField => write!(f, "Invalid string for Scalar, not in field"),
+        }
+    }
+}
+
+impl std::error::Error for ParseScalarError {}
+
+#[cfg(feature = "lossy-conversions")]
+/// The error returned when a floating point value cannot be converted into a `Scalar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The value is less than zero.
+    Negative,
+    /// The value has a fractional part.
+    NotAnInteger,
+    /// The value is NaN or infinite.
+    NotFinite,
+    /// The value is not less than `2^53`.
+    OutOfRange,
+}
+
+#[cfg(feature = "lossy-conversions")]
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Negative => write!(f, "Invalid value for Scalar, negative"),
+            Self::NotAnInteger => write!(f, "Invalid value for Scalar, not an integer"),
+            Self::NotFinite => write!(f, "Invalid value for Scalar, not finite"),
+            Self::OutOfRange => write!(f, "Invalid value for Scalar, not less than 2^53"),
+        }
+    }
+}
+
+#[cfg(feature = "lossy-conversions")]
+impl std::error::Error for ConversionError {}
+
 impl TryInto<Scalar> for blst_scalar {
     type Error = NotInFieldError;
 
@@ -415,6 +621,34 @@ impl_add_sub_assign!(Scalar);
 impl_mul!(Scalar);
 impl_mul_assign!(Scalar);
 
+impl Add<u64> for Scalar {
+    type Output = Scalar;
+
+    #[inline]
+    fn add(self, rhs: u64) -> Scalar {
+        self.add_u64(rhs)
+    }
+}
+
+impl Sub<u64> for Scalar {
+    type Output = Scalar;
+
+    #[inline]
+    fn sub(self, rhs: u64) -> Scalar {
+        self.sub_u64(rhs)
+    }
+}
+
+impl Mul<u64> for Scalar {
+    type Output = Scalar;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Scalar {
+        self.mul_u64(rhs)
+    }
+}
+
+/// Panics if `rhs` is zero, use [`Scalar::div_exact`] for untrusted divisors.
 impl<'a, 'b> Div<&'b Scalar> for &'a Scalar {
     type Output = Scalar;
 
@@ -662,6 +896,20 @@ impl PrimeFieldBits for Scalar {
     }
 }
 
+impl FromUniformBytes<64> for Scalar {
+    /// Reduces 64 little-endian bytes, see [`Scalar::from_bytes_wide`].
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        Scalar::from_bytes_wide(bytes)
+    }
+}
+
+impl FromUniformBytes<48> for Scalar {
+    /// Reduces 48 bytes of output keying material, see [`Scalar::from_okm`].
+    fn from_uniform_bytes(bytes: &[u8; 48]) -> Self {
+        Scalar::from_okm(bytes)
+    }
+}
+
 impl LowerHex for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let tmp = self.to_be_bytes();
@@ -863,8 +1111,8 @@ impl IsHigh for Scalar {
         let t = self.to_raw();
 
         let mut borrow = 0;
-        for i in 0..4 {
-            let (_, b) = sbb(HALF_MODULUS[i], t[i], borrow);
+        for (m, t) in Self::MODULUS_MINUS_ONE_DIV_TWO.iter().zip(t.iter()) {
+            let (_, b) = sbb(*m, *t, borrow);
             borrow = b;
         }
         ((borrow == u64::MAX) as u8).into()
@@ -948,16 +1196,12 @@ impl Reduce<U512> for Scalar {
     }
 }
 
-impl_from_bytes!(Scalar, |s: &Scalar| s.to_be_bytes(), |arr: &[u8]| {
-    let tmp: [u8; 32] = arr.try_into().map_err(|_| {
-        format!(
-            "Invalid number of bytes for Scalar, expected {}, found {}",
-            Scalar::BYTES,
-            arr.len()
-        )
-    })?;
-    Ok::<CtOption<Scalar>, String>(Scalar::from_be_bytes(&tmp))
-});
+impl_from_bytes!(
+    Scalar,
+    |s: &Scalar| s.to_be_bytes(),
+    |arr: &[u8]| Scalar::try_from_be_bytes(arr),
+    ScalarError
+);
 
 impl Scalar {
     /// Bytes to represent this field
@@ -966,6 +1210,27 @@ impl Scalar {
     pub const ZERO: Self = Self(blst_fr { l: [0, 0, 0, 0] });
     /// The multiplicative identity
     pub const ONE: Self = R;
+    /// The element `2`
+    pub const TWO: Self = Self(blst_fr {
+        l: [
+            0x0000_0003_ffff_fffc,
+            0xb109_6ff4_0006_9004,
+            0x3318_9fdf_d978_9fea,
+            0x3049_62b3_598a_0adf,
+        ],
+    });
+    /// The element `-1 = q - 1`
+    pub const MINUS_ONE: Self = Self(blst_fr {
+        l: [
+            0xffff_fffd_0000_0003,
+            0xfb38_ec08_fffb_13fc,
+            0x99ad_8818_1ce5_880f,
+            0x5bc8_f5f9_7cd8_77d8,
+        ],
+    });
+    /// The integer `(q - 1) / 2` in little-endian non-Montgomery form, the exponent
+    /// of Euler's criterion.
+    pub const MODULUS_MINUS_ONE_DIV_TWO: [u64; 4] = HALF_MODULUS;
 
     /// Attempts to convert a little-endian byte representation of
     /// a scalar into a `Scalar`, failing if the input is not canonical.
@@ -989,27 +1254,109 @@ impl Scalar {
         Self::from_le_bytes(&le_bytes)
     }
 
+    /// Converts a little-endian byte slice into a `Scalar`, reporting why the
+    /// input was rejected.
+    ///
+    /// This is not constant time, use [`Scalar::from_le_bytes`] for secret inputs.
+    pub fn try_from_le_bytes(bytes: &[u8]) -> Result<Scalar, ScalarError> {
+        let bytes =
+            <&[u8; Self::BYTES]>::try_from(bytes).map_err(|_| ScalarError::WrongLength {
+                expected: Self::BYTES,
+                found: bytes.len(),
+            })?;
+        Option::from(Self::from_le_bytes(bytes)).ok_or(ScalarError::NotCanonical)
+    }
+
+    /// Converts a big-endian byte slice into a `Scalar`, reporting why the input
+    /// was rejected.
+    ///
+    /// This is not constant time, use [`Scalar::from_be_bytes`] for secret inputs.
+    pub fn try_from_be_bytes(bytes: &[u8]) -> Result<Scalar, ScalarError> {
+        let bytes =
+            <&[u8; Self::BYTES]>::try_from(bytes).map_err(|_| ScalarError::WrongLength {
+                expected: Self::BYTES,
+                found: bytes.len(),
+            })?;
+        Option::from(Self::from_be_bytes(bytes)).ok_or(ScalarError::NotCanonical)
+    }
+
     /// Converts an element of `Scalar` into a byte representation in
     /// little-endian byte order.
     #[inline]
     pub fn to_le_bytes(&self) -> [u8; Self::BYTES] {
-        let mut out = [0u64; 4];
-        unsafe { blst_uint64_from_fr(out.as_mut_ptr(), &self.0) };
         let mut res = [0u8; Self::BYTES];
-        res[0..8].copy_from_slice(&out[0].to_le_bytes());
-        res[8..16].copy_from_slice(&out[1].to_le_bytes());
-        res[16..24].copy_from_slice(&out[2].to_le_bytes());
-        res[24..32].copy_from_slice(&out[3].to_le_bytes());
-
+        self.write_le(&mut res);
         res
     }
 
     /// Converts an element of `Scalar` into a byte representation in
     /// big-endian byte order.
     pub fn to_be_bytes(&self) -> [u8; Self::BYTES] {
-        let mut bytes = self.to_le_bytes();
-        bytes.reverse();
-        bytes
+        let mut res = [0u8; Self::BYTES];
+        self.write_be(&mut res);
+        res
+    }
+
+    /// Writes the little-endian byte representation of `self` into `out`.
+    #[inline]
+    pub fn write_le(&self, out: &mut [u8; Self::BYTES]) {
+        let mut limbs = [0u64; 4];
+        unsafe { blst_uint64_from_fr(limbs.as_mut_ptr(), &self.0) };
+        out[0..8].copy_from_slice(&limbs[0].to_le_bytes());
+        out[8..16].copy_from_slice(&limbs[1].to_le_bytes());
+        out[16..24].copy_from_slice(&limbs[2].to_le_bytes());
+        out[24..32].copy_from_slice(&limbs[3].to_le_bytes());
+    }
+
+    /// Writes the big-endian byte representation of `self` into `out`.
+    #[inline]
+    pub fn write_be(&self, out: &mut [u8; Self::BYTES]) {
+        self.write_le(out);
+        out.reverse();
+    }
+
+    /// Writes the little-endian byte representation of each scalar to `w`,
+    /// reusing a single buffer.
+    pub fn write_all_le<W: std::io::Write>(scalars: &[Scalar], w: &mut W) -> std::io::Result<()> {
+        let mut buf = [0u8; Self::BYTES];
+        for s in scalars {
+            s.write_le(&mut buf);
+            w.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the number of scalars as a little-endian `u64` followed by the
+    /// big-endian byte representation of each scalar to `w`.
+    pub fn write_slice<W: std::io::Write>(scalars: &[Scalar], w: &mut W) -> std::io::Result<()> {
+        w.write_all(&(scalars.len() as u64).to_le_bytes())?;
+        let mut buf = [0u8; Self::BYTES];
+        for s in scalars {
+            s.write_be(&mut buf);
+            w.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Reads scalars written by [`Scalar::write_slice`] from `r`, failing with
+    /// [`std::io::ErrorKind::InvalidData`] if any encoding is not canonical.
+    pub fn read_slice<R: std::io::Read>(r: &mut R) -> std::io::Result<Vec<Scalar>> {
+        let mut len = [0u8; 8];
+        r.read_exact(&mut len)?;
+        let len = usize::try_from(u64::from_le_bytes(len))
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        // Don't trust the length prefix for the initial allocation
+        let mut scalars = Vec::with_capacity(len.min(1024));
+        let mut buf = [0u8; Self::BYTES];
+        for _ in 0..len {
+            r.read_exact(&mut buf)?;
+            let s = Option::<Scalar>::from(Self::from_be_bytes(&buf)).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, NotInFieldError)
+            })?;
+            scalars.push(s);
+        }
+        Ok(scalars)
     }
 
     /// Converts from an integer represented in little endian
@@ -1025,14 +1372,79 @@ impl Scalar {
         CtOption::new(Scalar(out), is_some)
     }
 
-    /// Converts from an integer represented in little endian
-    /// into its (congruent) `Scalar` representation.
-    pub const fn from_raw_unchecked(val: [u64; 4]) -> Self {
+    /// Converts from an integer represented in little endian, reducing it modulo
+    /// `q` instead of failing like [`Scalar::from_raw`] when it is not canonical.
+    pub fn from_raw_reduce(limbs: [u64; 4]) -> Self {
+        #[cfg(target_pointer_width = "64")]
+        {
+            <Self as Reduce<U256>>::reduce(U256::from_words(limbs))
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            let mut words = [0u32; 8];
+            for (i, limb) in limbs.iter().enumerate() {
+                words[2 * i] = *limb as u32;
+                words[2 * i + 1] = (*limb >> 32) as u32;
+            }
+            <Self as Reduce<U256>>::reduce(U256::from_words(words))
+        }
+    }
+
+    /// Interprets `limbs` as an integer represented in little endian and converts it
+    /// into Montgomery form, failing if it is not less than the modulus.
+    ///
+    /// This is the same as [`Scalar::from_raw`], and is what most callers want.
+    /// Contrast with [`Scalar::from_montgomery_limbs_unchecked`], which stores the
+    /// limbs as they are.
+    pub fn from_integer(limbs: [u64; 4]) -> CtOption<Self> {
+        Self::from_raw(limbs)
+    }
+
+    /// Stores `limbs` directly as the internal Montgomery representation, without
+    /// any conversion or check. The resulting value is `limbs * R^-1 mod q`, not
+    /// `limbs`.
+    ///
+    /// This is only useful for constants that are already in Montgomery form, use
+    /// [`Scalar::from_integer`] to convert an integer.
+    pub const fn from_montgomery_limbs_unchecked(limbs: [u64; 4]) -> Self {
         Self(blst_fr {
-            l: [val[0], val[1], val[2], val[3]],
+            l: [limbs[0], limbs[1], limbs[2], limbs[3]],
         })
     }
 
+    /// Returns the internal Montgomery representation `self * R mod q` as is.
+    ///
+    /// Unlike [`Scalar::to_raw`], this does not convert back to the integer value,
+    /// so it is cheaper when caching values that are only ever read back with
+    /// [`Scalar::from_montgomery_limbs`].
+    pub const fn to_montgomery_limbs(&self) -> [u64; 4] {
+        self.0.l
+    }
+
+    /// Stores `limbs`, as returned by [`Scalar::to_montgomery_limbs`], directly as
+    /// the internal Montgomery representation, failing if they are not less than
+    /// the modulus.
+    ///
+    /// Use [`Scalar::from_montgomery_limbs_unchecked`] to skip the check, or
+    /// [`Scalar::from_raw`] to convert an integer.
+    pub fn from_montgomery_limbs(limbs: [u64; 4]) -> CtOption<Self> {
+        let mut raw = blst_scalar::default();
+
+        unsafe { blst_scalar_from_uint64(&mut raw, limbs.as_ptr()) };
+        let is_some = Choice::from(unsafe { blst_scalar_fr_check(&raw) as u8 });
+
+        CtOption::new(Self::from_montgomery_limbs_unchecked(limbs), is_some)
+    }
+
+    /// Stores `val` directly as the internal Montgomery representation, without
+    /// any conversion or check.
+    #[deprecated(
+        note = "does not convert into Montgomery form, use `from_montgomery_limbs_unchecked` or `from_integer`"
+    )]
+    pub const fn from_raw_unchecked(val: [u64; 4]) -> Self {
+        Self::from_montgomery_limbs_unchecked(val)
+    }
+
     /// Converts from a scalar to an integer represented in little endian
     pub fn to_raw(&self) -> [u64; 4] {
         let mut out = [0u64; 4];
@@ -1040,6 +1452,221 @@ impl Scalar {
         out
     }
 
+    /// Raises this element to the power of the canonical integer representation
+    /// of `exp`. This is variable time with respect to `exp`.
+    pub fn pow_vartime_scalar(&self, exp: &Scalar) -> Self {
+        self.pow_vartime(exp.to_raw())
+    }
+
+    /// Raises this element to the power of `exp`, a big-endian integer of any
+    /// length. This is variable time with respect to `exp`.
+    pub fn pow_vartime_be_bytes(&self, exp: &[u8]) -> Self {
+        let mut res = Self::ONE;
+        for byte in exp {
+            for i in (0..8).rev() {
+                res.square_assign();
+                if (byte >> i) & 1 == 1 {
+                    res *= self;
+                }
+            }
+        }
+        res
+    }
+
+    /// Raises this element to the power of the signed integer `exp`, inverting it
+    /// first if `exp` is negative. Returns `none` if `exp` is negative and `self`
+    /// is zero. This is variable time with respect to `exp`.
+    pub fn pow_signed(&self, exp: i64) -> CtOption<Scalar> {
+        if exp < 0 {
+            self.invert()
+                .map(|inv| inv.pow_vartime([exp.unsigned_abs()]))
+        } else {
+            CtOption::new(self.pow_vartime([exp as u64]), Choice::from(1))
+        }
+    }
+
+    /// Returns `[1, self, self^2, ..., self^(n - 1)]`.
+    pub fn powers(&self, n: usize) -> Vec<Scalar> {
+        let mut out = vec![Self::ZERO; n];
+        self.powers_into(&mut out);
+        out
+    }
+
+    /// Writes `self^i` into `out[i]` for every index of `out`, using one
+    /// multiplication per element.
+    pub fn powers_into(&self, out: &mut [Scalar]) {
+        let mut acc = Self::ONE;
+        for power in out.iter_mut() {
+            *power = acc;
+            acc *= self;
+        }
+    }
+
+    /// Samples a scalar uniformly from `[0, 2^bits)`, for example to generate
+    /// challenges with a fixed security level.
+    ///
+    /// If `bits` is at least 255 this is the same as [`Field::random`], since
+    /// `2^255` is larger than the modulus.
+    pub fn random_bits(mut rng: impl RngCore, bits: u32) -> Self {
+        if bits >= 255 {
+            return Self::random(rng);
+        }
+
+        let mut bytes = [0u8; Self::BYTES];
+        let len = (bits as usize).div_ceil(8);
+        rng.fill_bytes(&mut bytes[..len]);
+        if let Some(top) = bytes[..len].last_mut() {
+            *top &= 0xff >> (8 * len as u32 - bits);
+        }
+        Self::from_le_bytes(&bytes).unwrap()
+    }
+
+    /// Samples `n` uniformly random scalars, filling a single buffer from `rng`
+    /// and reducing each 48-byte chunk with [`Scalar::from_okm`].
+    pub fn random_vec(mut rng: impl RngCore, n: usize) -> Vec<Scalar> {
+        let mut okm = vec![0u8; 48 * n];
+        rng.fill_bytes(&mut okm);
+        okm.chunks_exact(48)
+            .map(|chunk| Self::from_okm(<&[u8; 48]>::try_from(chunk).unwrap()))
+            .collect()
+    }
+
+    /// Same as [`Scalar::random_vec`], but resamples any zero element.
+    pub fn random_nonzero_vec(mut rng: impl RngCore, n: usize) -> Vec<Scalar> {
+        let mut out = Self::random_vec(&mut rng, n);
+        for s in out.iter_mut() {
+            while bool::from(s.is_zero()) {
+                *s = Self::random(&mut rng);
+            }
+        }
+        out
+    }
+
+    /// Converts `self` into its canonical decimal representation, which can be
+    /// parsed back with [`FromStr`].
+    pub fn to_decimal_string(&self) -> String {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+        let mut limbs = self.to_raw();
+        let mut chunks = Vec::new();
+        loop {
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let t = (rem << 64) | (*limb as u128);
+                *limb = (t / CHUNK) as u64;
+                rem = t % CHUNK;
+            }
+            chunks.push(rem as u64);
+            if limbs == [0u64; 4] {
+                break;
+            }
+        }
+
+        let mut out = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            out.push_str(&format!("{:019}", chunk));
+        }
+        out
+    }
+
+    /// Returns the 256 bits of the canonical representation of `self`,
+    /// least significant bit first.
+    pub fn bits_le(&self) -> Vec<bool> {
+        self.to_le_bytes()
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    /// Returns the 256 bits of the canonical representation of `self`,
+    /// most significant bit first.
+    pub fn bits_be(&self) -> Vec<bool> {
+        let mut bits = self.bits_le();
+        bits.reverse();
+        bits
+    }
+
+    /// Packs a little-endian bit string into a `Scalar`, failing if the value is
+    /// not less than the modulus.
+    ///
+    /// Also returns `none` if `bits` has more than 255 elements, the bit length of
+    /// the modulus, even if the extra bits are all `false`.
+    pub fn from_bits_le(bits: &[bool]) -> CtOption<Scalar> {
+        let fits = bits.len() <= Self::NUM_BITS as usize;
+        let mut bytes = [0u8; Self::BYTES];
+        for (i, bit) in bits.iter().take(Self::NUM_BITS as usize).enumerate() {
+            bytes[i / 8] |= (*bit as u8) << (i % 8);
+        }
+        let s = Self::from_le_bytes(&bytes);
+        CtOption::new(
+            s.unwrap_or(Self::ZERO),
+            s.is_some() & Choice::from(fits as u8),
+        )
+    }
+
+    /// Returns the low `n` bits of the canonical representation of `self`, least
+    /// significant bit first, or `None` if the value does not fit in `n` bits.
+    ///
+    /// This is the inverse of [`Scalar::from_bits_le`].
+    pub fn to_bits_le_fixed(&self, n: usize) -> Option<Vec<bool>> {
+        let mut bits = self.bits_le();
+        if bits.iter().skip(n).any(|bit| *bit) {
+            return None;
+        }
+        bits.resize(n, false);
+        Some(bits)
+    }
+
+    /// Returns the low `k` bits of the canonical representation of `self`, i.e.
+    /// `self mod 2^k`.
+    pub fn low_bits(&self, k: u32) -> Scalar {
+        let mut limbs = self.to_raw();
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 64 * i as u32;
+            if k <= start {
+                *limb = 0;
+            } else if k < start + 64 {
+                *limb &= (1u64 << (k - start)) - 1;
+            }
+        }
+        Scalar::from_raw(limbs).unwrap()
+    }
+
+    /// Returns the canonical representation of `self` shifted right by `k` bits,
+    /// i.e. `self / 2^k` rounded down, so that
+    /// `self == self.low_bits(k) + self.high_bits(k).shl(k)`.
+    pub fn high_bits(&self, k: u32) -> Scalar {
+        let limbs = self.to_raw();
+        let mut out = [0u64; 4];
+        let (words, bits) = ((k / 64) as usize, k % 64);
+        for i in 0..4usize.saturating_sub(words) {
+            out[i] = limbs[i + words] >> bits;
+            if bits > 0 && i + words + 1 < 4 {
+                out[i] |= limbs[i + words + 1] << (64 - bits);
+            }
+        }
+        Scalar::from_raw(out).unwrap()
+    }
+
+    /// Converts `self` into its minimal big-endian byte representation, without
+    /// leading zero bytes. Zero is represented by an empty vector.
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        let len = (self.num_bits() as usize).div_ceil(8);
+        self.to_be_bytes()[Self::BYTES - len..].to_vec()
+    }
+
+    /// Attempts to convert a big-endian byte representation of at most
+    /// `Scalar::BYTES` bytes, such as the output of [`Scalar::to_be_bytes_trimmed`],
+    /// into a `Scalar`, failing if the input is too long or not canonical.
+    pub fn from_be_bytes_trimmed(bytes: &[u8]) -> CtOption<Scalar> {
+        if bytes.len() > Self::BYTES {
+            return CtOption::new(Self::ZERO, Choice::from(0u8));
+        }
+        let mut be_bytes = [0u8; Self::BYTES];
+        be_bytes[Self::BYTES - bytes.len()..].copy_from_slice(bytes);
+        Self::from_be_bytes(&be_bytes)
+    }
+
     /// Create a new [`Scalar`] from the provided big endian hex string.
     pub fn from_be_hex(hex: &str) -> CtOption<Self> {
         let mut buf = [0u8; Self::BYTES];
@@ -1047,6 +1674,32 @@ impl Scalar {
         Self::from_be_bytes(&buf)
     }
 
+    /// Create a new [`Scalar`] from the provided big endian hex string, optionally
+    /// prefixed with `0x`.
+    ///
+    /// Unlike [`Scalar::from_be_hex`], the string must contain exactly
+    /// `2 * Scalar::BYTES` hex digits and each failure is reported as a distinct
+    /// [`HexError`].
+    pub fn from_be_hex_checked(hex: &str) -> Result<Self, HexError> {
+        let (prefix_len, hex) = match hex.strip_prefix("0x") {
+            Some(rest) => (2, rest),
+            None => (0, hex),
+        };
+        if hex.len() != 2 * Self::BYTES {
+            return Err(HexError::InvalidLength {
+                expected: 2 * Self::BYTES,
+                found: hex.len(),
+            });
+        }
+        let mut buf = [0u8; Self::BYTES];
+        util::try_decode_hex_into_slice(&mut buf, hex.as_bytes()).map_err(|index| {
+            HexError::InvalidCharacter {
+                index: index + prefix_len,
+            }
+        })?;
+        Option::from(Self::from_be_bytes(&buf)).ok_or(HexError::NotInField)
+    }
+
     /// Create a new [`Scalar`] from the provided little endian hex string.
     pub fn from_le_hex(hex: &str) -> CtOption<Self> {
         let mut buf = [0u8; Self::BYTES];
@@ -1063,14 +1716,8 @@ impl Scalar {
     }
 
     pub fn legendre(&self) -> i8 {
-        const MOD_MINUS_1_OVER_2: [u64; 4] = [
-            0x7fffffff80000000,
-            0xa9ded2017fff2dff,
-            0x199cec0404d0ec02,
-            0x39f6d3a994cebea4,
-        ];
         // s = self^((modulus - 1) // 2)
-        let s = self.pow_vartime(MOD_MINUS_1_OVER_2);
+        let s = self.pow_vartime(Self::MODULUS_MINUS_ONE_DIV_TWO);
         if s == Self::ZERO {
             0
         } else if s == Self::ONE {
@@ -1080,39 +1727,571 @@ impl Scalar {
         }
     }
 
-    pub fn char() -> <Self as PrimeField>::Repr {
-        MODULUS_REPR
+    /// Converts a signed integer into a `Scalar`, mapping negative values to the
+    /// upper half of the field.
+    pub fn from_i128(x: i128) -> Self {
+        let abs = Self::from(x.unsigned_abs());
+        if x < 0 {
+            -abs
+        } else {
+            abs
+        }
     }
 
-    pub fn num_bits(&self) -> u32 {
-        let mut ret = 256;
-        for i in self.to_be_bytes().iter() {
-            let leading = i.leading_zeros();
-            ret -= leading;
-            if leading != 8 {
-                break;
+    /// The inverse of [`Scalar::from_i128`], treating the upper half of the field
+    /// as negative values. Returns `None` if the value is outside of `[-2^127, 2^127)`.
+    pub fn to_signed_i128(&self) -> Option<i128> {
+        let is_negative = bool::from(self.is_high());
+        let abs = if is_negative { -self } else { *self }.to_raw();
+        if abs[2] != 0 || abs[3] != 0 {
+            return None;
+        }
+        let abs = abs[0] as u128 | ((abs[1] as u128) << 64);
+        if is_negative {
+            0i128.checked_sub_unsigned(abs)
+        } else {
+            i128::try_from(abs).ok()
+        }
+    }
+
+    /// Computes the Jacobi symbol `(a / n)` for an odd `n`, given as little endian
+    /// limbs. This generalizes [`Scalar::legendre`], which is the case `n = q`.
+    ///
+    /// This is variable time. Panics if `n` is even.
+    pub fn jacobi(a: &Scalar, n: &[u64; 4]) -> i8 {
+        assert_eq!(n[0] & 1, 1, "n must be odd");
+
+        let to_uint = |limbs: &[u64; 4]| {
+            let mut bytes = [0u8; 32];
+            for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs.iter()) {
+                chunk.copy_from_slice(&limb.to_le_bytes());
+            }
+            U256::from_le_slice(&bytes)
+        };
+        let low_bits = |x: &U256| (x.as_words()[0] & 7) as u8;
+
+        let mut a = to_uint(&a.to_raw());
+        let mut n = to_uint(n);
+        let mut t = 1;
+        while a != U256::ZERO {
+            while low_bits(&a) & 1 == 0 {
+                a = a.shr_vartime(1);
+                if matches!(low_bits(&n), 3 | 5) {
+                    t = -t;
+                }
             }
+            // Quadratic reciprocity, both are odd
+            if a < n {
+                core::mem::swap(&mut a, &mut n);
+                if low_bits(&a) & 3 == 3 && low_bits(&n) & 3 == 3 {
+                    t = -t;
+                }
+            }
+            a = a.wrapping_sub(&n);
         }
 
-        ret
+        if n == U256::ONE {
+            t
+        } else {
+            0
+        }
     }
 
-    /// Multiplies `self` with `3`, returning the result.
-    pub fn mul3(&self) -> Self {
-        let mut out = blst_fr::default();
+    /// Computes the square root of `self` with a variable-time Tonelli–Shanks,
+    /// returning `none` if `self` is not a quadratic residue.
+    ///
+    /// Non-residues are rejected early via the Legendre symbol and the main loop
+    /// depends on the input, so this must only be used on public values. Use
+    /// [`Field::sqrt`] for secret values.
+    pub fn sqrt_vartime(&self) -> CtOption<Self> {
+        // (t - 1) // 2 = 6104339283789297388802252303364915521546564123189034618274734669823
+        const T_MINUS_1_OVER_2: [u64; 4] = [
+            0x7fff_2dff_7fff_ffff,
+            0x04d0_ec02_a9de_d201,
+            0x94ce_bea4_199c_ec04,
+            0x0000_0000_39f6_d3a9,
+        ];
 
-        unsafe { blst_fr_mul_by_3(&mut out, &self.0) };
+        match self.legendre() {
+            0 => return CtOption::new(Self::ZERO, Choice::from(1u8)),
+            -1 => return CtOption::new(Self::ZERO, Choice::from(0u8)),
+            _ => {}
+        }
 
-        Scalar(out)
-    }
+        let w = self.pow_vartime(T_MINUS_1_OVER_2);
+        // x = self^((t + 1) / 2), b = self^t
+        let mut x = self * w;
+        let mut b = x * w;
+        let mut c = ROOT_OF_UNITY;
+        let mut m = S;
+
+        while b != Self::ONE {
+            // Find the least i such that b^(2^i) == 1
+            let mut i = 1;
+            let mut b2 = b.square();
+            while b2 != Self::ONE {
+                b2.square_assign();
+                i += 1;
+            }
 
-    /// Left shift `self` by `count`, returning the result.
-    pub fn shl(&self, count: usize) -> Self {
-        let mut out = blst_fr::default();
+            let mut w = c;
+            for _ in 0..m - i - 1 {
+                w.square_assign();
+            }
+            x *= w;
+            c = w.square();
+            b *= c;
+            m = i;
+        }
 
-        unsafe { blst_fr_lshift(&mut out, &self.0, count) };
+        CtOption::new(x, Choice::from(1u8))
+    }
 
-        Scalar(out)
+    /// Computes the Legendre symbol of each element of `values`, agreeing
+    /// element-wise with [`Scalar::legendre`].
+    pub fn batch_legendre(values: &[Scalar]) -> Vec<i8> {
+        values.iter().map(Scalar::legendre).collect()
+    }
+
+    /// Inverts every nonzero element of `values` in place using Montgomery's trick,
+    /// leaving zeros untouched. `scratch` holds the running products so no heap
+    /// allocation is done.
+    ///
+    /// Returns whether all of `values` were nonzero.
+    ///
+    /// Panics if `scratch` is not the same length as `values`.
+    pub fn batch_invert_scratch(values: &mut [Scalar], scratch: &mut [Scalar]) -> Choice {
+        assert_eq!(values.len(), scratch.len());
+
+        let mut all_nonzero = Choice::from(1u8);
+        let mut acc = Self::ONE;
+        for (value, tmp) in values.iter().zip(scratch.iter_mut()) {
+            *tmp = acc;
+            let skip = value.is_zero();
+            all_nonzero &= !skip;
+            acc = Self::conditional_select(&(acc * value), &acc, skip);
+        }
+
+        // acc is a product of nonzero elements so it is always invertible
+        acc = acc.invert().unwrap();
+
+        for (value, tmp) in values.iter_mut().zip(scratch.iter()).rev() {
+            let skip = value.is_zero();
+            let inv = *tmp * acc;
+            acc = Self::conditional_select(&(acc * *value), &acc, skip);
+            *value = Self::conditional_select(&inv, value, skip);
+        }
+
+        all_nonzero
+    }
+
+    /// Divides `numerators` by `denominators` elementwise, inverting all the
+    /// denominators at once with [`Scalar::batch_invert_scratch`].
+    ///
+    /// Returns `none` if any denominator is zero. Panics if the slices have
+    /// different lengths.
+    pub fn invert_and_mul(numerators: &[Scalar], denominators: &[Scalar]) -> CtOption<Vec<Scalar>> {
+        assert_eq!(numerators.len(), denominators.len());
+
+        let mut out = denominators.to_vec();
+        let mut scratch = vec![Self::ZERO; out.len()];
+        let all_nonzero = Self::batch_invert_scratch(&mut out, &mut scratch);
+        for (value, numerator) in out.iter_mut().zip(numerators.iter()) {
+            *value *= numerator;
+        }
+        CtOption::new(out, all_nonzero)
+    }
+
+    pub fn char() -> <Self as PrimeField>::Repr {
+        MODULUS_REPR
+    }
+
+    pub fn num_bits(&self) -> u32 {
+        let mut ret = 256;
+        for i in self.to_be_bytes().iter() {
+            let leading = i.leading_zeros();
+            ret -= leading;
+            if leading != 8 {
+                break;
+            }
+        }
+
+        ret
+    }
+
+    /// Returns `self + rhs` in the field, and whether the integer sum of the
+    /// canonical representations is at least `2^bound_bits`.
+    ///
+    /// This is useful for emulating range checked fixed-width integer arithmetic.
+    pub fn overflowing_add_bounded(&self, rhs: &Scalar, bound_bits: u32) -> (Scalar, bool) {
+        let a = self.to_raw();
+        let b = rhs.to_raw();
+
+        // The integer sum has at most 256 bits
+        let mut sum = [0u64; 4];
+        let mut carry = false;
+        for i in 0..4 {
+            let (t, c1) = a[i].overflowing_add(b[i]);
+            let (t, c2) = t.overflowing_add(carry as u64);
+            sum[i] = t;
+            carry = c1 | c2;
+        }
+        let bits = match sum.iter().rposition(|limb| *limb != 0) {
+            Some(i) => 64 * i as u32 + 64 - sum[i].leading_zeros(),
+            None => 0,
+        };
+
+        (self + rhs, bits > bound_bits)
+    }
+
+    /// Returns the inverse of `self`, or zero if `self` is zero.
+    ///
+    /// The selection is done with `ConditionallySelectable`, so this runs in
+    /// constant time.
+    pub fn invert_or_zero(&self) -> Scalar {
+        self.invert().unwrap_or(Self::ZERO)
+    }
+
+    /// Divides `self` by `rhs`, returning `none` if `rhs` is zero.
+    ///
+    /// Unlike the `/` operator, which panics on a zero divisor, this is safe to use
+    /// with untrusted input.
+    pub fn div_exact(&self, rhs: &Scalar) -> CtOption<Scalar> {
+        rhs.invert().map(|inv| self * inv)
+    }
+
+    /// Divides `self` by `2`, i.e. multiplies it with [`PrimeField::TWO_INV`].
+    pub fn halve(&self) -> Self {
+        self * <Self as PrimeField>::TWO_INV
+    }
+
+    /// Returns `self.halve()` if `choice` is 1 and `self` otherwise, in constant
+    /// time.
+    pub fn conditional_halve(&self, choice: Choice) -> Self {
+        Self::conditional_select(self, &self.halve(), choice)
+    }
+
+    /// Returns `self.double()` if `choice` is 1 and `self` otherwise, in constant
+    /// time.
+    pub fn conditional_double(&self, choice: Choice) -> Self {
+        Self::conditional_select(self, &self.double(), choice)
+    }
+
+    /// Multiplies `self` with `3`, returning the result.
+    pub fn mul3(&self) -> Self {
+        let mut out = blst_fr::default();
+
+        unsafe { blst_fr_mul_by_3(&mut out, &self.0) };
+
+        Scalar(out)
+    }
+
+    /// Multiplies `self` with the small constant `c`, returning the result.
+    ///
+    /// Powers of two use a modular shift and `3` uses [`Scalar::mul3`], all other
+    /// constants fall back to a Montgomery multiplication. This is variable time
+    /// with respect to `c`.
+    pub fn mul_u64(&self, c: u64) -> Self {
+        match c {
+            0 => Self::ZERO,
+            1 => *self,
+            3 => self.mul3(),
+            c if c.is_power_of_two() => self.shl(c.trailing_zeros() as usize),
+            c => self * Scalar::from(c),
+        }
+    }
+
+    /// Converts the small constant `c` directly from a single limb, without going
+    /// through a byte representation.
+    #[inline]
+    fn from_u64_limb(c: u64) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst_fr_from_uint64(&mut out, [c, 0, 0, 0].as_ptr()) };
+        Scalar(out)
+    }
+
+    /// Adds the small constant `c` to `self`, returning the result.
+    pub fn add_u64(&self, c: u64) -> Self {
+        self + Self::from_u64_limb(c)
+    }
+
+    /// Subtracts the small constant `c` from `self`, returning the result.
+    pub fn sub_u64(&self, c: u64) -> Self {
+        self - Self::from_u64_limb(c)
+    }
+
+    /// Negates `self` in place if `choice` is set, otherwise leaves it unchanged.
+    ///
+    /// The negation is gated by `blst_fr_cneg` so it runs in constant time.
+    pub fn conditional_negate(&mut self, choice: Choice) {
+        unsafe { blst_fr_cneg(&mut self.0, &self.0, bool::from(choice)) };
+    }
+
+    /// Negates each element of `values` whose corresponding `choices` entry is set.
+    ///
+    /// Panics if `values` and `choices` have different lengths.
+    pub fn conditional_negate_slice(values: &mut [Scalar], choices: &[Choice]) {
+        assert_eq!(values.len(), choices.len());
+        for (value, choice) in values.iter_mut().zip(choices.iter()) {
+            value.conditional_negate(*choice);
+        }
+    }
+
+    /// Returns `table[index]`, or zero if `index` is out of range.
+    ///
+    /// Every element of `table` is read and combined with `conditional_assign`, so
+    /// the memory access pattern does not depend on `index`.
+    pub fn conditional_lookup(table: &[Scalar], index: usize) -> Scalar {
+        let mut out = Self::ZERO;
+        for (i, value) in table.iter().enumerate() {
+            out.conditional_assign(value, (i as u64).ct_eq(&(index as u64)));
+        }
+        out
+    }
+
+    /// Adds the inner product `sum(coeffs[i] * values[i])` to `acc` in place.
+    ///
+    /// Panics if `coeffs` and `values` have different lengths.
+    pub fn multiply_accumulate(acc: &mut Scalar, coeffs: &[Scalar], values: &[Scalar]) {
+        assert_eq!(coeffs.len(), values.len());
+        for (coeff, value) in coeffs.iter().zip(values.iter()) {
+            *acc += coeff * value;
+        }
+    }
+
+    /// Returns the inner product $$\sum_i a_i b_i$$ of `a` and `b`.
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    pub fn sum_of_products(a: &[Scalar], b: &[Scalar]) -> Scalar {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    /// Returns the product of `matrix`, given as a list of rows, and `vector`.
+    ///
+    /// Panics if any row of `matrix` does not have the same length as `vector`.
+    pub fn matrix_vector_mul(matrix: &[Vec<Scalar>], vector: &[Scalar]) -> Vec<Scalar> {
+        matrix
+            .iter()
+            .map(|row| Self::sum_of_products(row, vector))
+            .collect()
+    }
+
+    /// Returns the elementwise product of `a` and `b`.
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    pub fn hadamard(a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b.iter()).map(|(a, b)| a * b).collect()
+    }
+
+    /// Multiplies each element of `a` by the corresponding element of `b` in place.
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    pub fn hadamard_assign(a: &mut [Scalar], b: &[Scalar]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter_mut().zip(b.iter()) {
+            *a *= b;
+        }
+    }
+
+    /// Writes `a` into `dst` if `choice` is 0, or `b` if `choice` is 1, in
+    /// constant time.
+    ///
+    /// Panics if `dst`, `a` and `b` do not all have the same length.
+    pub fn conditional_select_slice(
+        dst: &mut [Scalar],
+        a: &[Scalar],
+        b: &[Scalar],
+        choice: Choice,
+    ) {
+        assert_eq!(dst.len(), a.len());
+        assert_eq!(dst.len(), b.len());
+        for ((d, a), b) in dst.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *d = Scalar::conditional_select(a, b, choice);
+        }
+    }
+
+    /// Adds each element of `src` to the corresponding element of `dst` in place.
+    ///
+    /// Panics if `dst` and `src` have different lengths.
+    pub fn add_assign_slice(dst: &mut [Scalar], src: &[Scalar]) {
+        assert_eq!(dst.len(), src.len());
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d += s;
+        }
+    }
+
+    /// Subtracts each element of `src` from the corresponding element of `dst` in
+    /// place.
+    ///
+    /// Panics if `dst` and `src` have different lengths.
+    pub fn sub_assign_slice(dst: &mut [Scalar], src: &[Scalar]) {
+        assert_eq!(dst.len(), src.len());
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d -= s;
+        }
+    }
+
+    /// Adds `a` and `b` as coefficient vectors, padding the shorter one with zeros.
+    pub fn add_vectors(a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+        let mut out = a.to_vec();
+        out.resize(a.len().max(b.len()), Self::ZERO);
+        Self::add_assign_slice(&mut out[..b.len()], b);
+        out
+    }
+
+    /// Subtracts `b` from `a` as coefficient vectors, padding the shorter one with
+    /// zeros.
+    pub fn sub_vectors(a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+        let mut out = a.to_vec();
+        out.resize(a.len().max(b.len()), Self::ZERO);
+        Self::sub_assign_slice(&mut out[..b.len()], b);
+        out
+    }
+
+    /// Computes `y[i] = a * x[i] + y[i]` for every index in place.
+    ///
+    /// Panics if `x` and `y` have different lengths.
+    pub fn axpy(a: &Scalar, x: &[Scalar], y: &mut [Scalar]) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y.iter_mut()) {
+            *y += a * x;
+        }
+    }
+
+    /// Multiplies each element of `values` by `factor` in place.
+    pub fn scale_slice(values: &mut [Scalar], factor: &Scalar) {
+        for value in values.iter_mut() {
+            *value *= factor;
+        }
+    }
+
+    /// Divides evaluations on a coset by the vanishing polynomial of the domain.
+    ///
+    /// `evals` are the evaluations of a polynomial at the points
+    /// $$g \omega^i$$, where `g` is `coset_shift` and $$\omega$$ is the primitive
+    /// `n`-th root of unity for `n = evals.len()`. The vanishing polynomial
+    /// $$Z_H(x) = x^n - 1$$ of the subgroup $$H$$ generated by $$\omega$$ is the
+    /// constant $$g^n - 1$$ on that coset, so every element is multiplied by its
+    /// inverse.
+    ///
+    /// The crate has no evaluation domain type, so the domain is given by its size
+    /// `evals.len()` and the coset by `coset_shift`.
+    ///
+    /// Returns a falsy `Choice` and leaves `evals` unchanged if $$g^n = 1$$, i.e. the
+    /// coset is $$H$$ itself. Panics if `evals.len()` is not a power of two.
+    pub fn divide_by_vanishing(evals: &mut [Scalar], coset_shift: &Scalar) -> Choice {
+        assert!(evals.len().is_power_of_two());
+        let z_inv = (coset_shift.pow_vartime([evals.len() as u64]) - Self::ONE).invert();
+        let is_some = z_inv.is_some();
+        let factor = Scalar::conditional_select(&Self::ONE, &z_inv.unwrap_or(Self::ONE), is_some);
+        Self::scale_slice(evals, &factor);
+        is_some
+    }
+
+    /// Returns the primitive `2^log_n`-th root of unity, or `None` if `log_n > S`.
+    pub fn root_of_unity(log_n: u32) -> Option<Scalar> {
+        Self::root_of_unity_from(ROOT_OF_UNITY, log_n)
+    }
+
+    /// Returns the inverse of the primitive `2^log_n`-th root of unity returned by
+    /// [`Scalar::root_of_unity`], or `None` if `log_n > S`.
+    pub fn root_of_unity_inv(log_n: u32) -> Option<Scalar> {
+        Self::root_of_unity_from(<Self as PrimeField>::ROOT_OF_UNITY_INV, log_n)
+    }
+
+    fn root_of_unity_from(root: Scalar, log_n: u32) -> Option<Scalar> {
+        if log_n > S {
+            return None;
+        }
+        let mut out = root;
+        for _ in log_n..S {
+            out.square_assign();
+        }
+        Some(out)
+    }
+
+    /// Returns the width-`window` non-adjacent form of `self`, least significant
+    /// digit first.
+    ///
+    /// Every digit is either zero or odd and in `[-2^(window-1) + 1, 2^(window-1) - 1]`,
+    /// and any `window` consecutive digits contain at most one non-zero digit.
+    ///
+    /// Panics if `window` is not in `2..=8`.
+    pub fn to_wnaf(&self, window: usize) -> Vec<i8> {
+        assert!((2..=8).contains(&window), "window must be in 2..=8");
+
+        let mut limbs = [0u64; 5];
+        limbs[..4].copy_from_slice(&self.to_raw());
+
+        let width = 1u64 << window;
+        let window_mask = width - 1;
+
+        let mut wnaf = Vec::with_capacity(Self::NUM_BITS as usize + window);
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < Self::NUM_BITS as usize {
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+            let bit_buf = if bit_idx + window < 64 {
+                limbs[u64_idx] >> bit_idx
+            } else {
+                (limbs[u64_idx] >> bit_idx) | (limbs[u64_idx + 1] << (64 - bit_idx))
+            };
+
+            let window_val = carry + (bit_buf & window_mask);
+            if window_val & 1 == 0 {
+                wnaf.push(0);
+                pos += 1;
+            } else {
+                if window_val < width / 2 {
+                    carry = 0;
+                    wnaf.push(window_val as i8);
+                } else {
+                    carry = 1;
+                    wnaf.push((window_val as i64 - width as i64) as i8);
+                }
+                wnaf.resize(wnaf.len() + window - 1, 0);
+                pos += window;
+            }
+        }
+        if carry != 0 {
+            wnaf.push(1);
+        }
+
+        wnaf
+    }
+
+    /// Decomposes `self` into four digits `k_i < |x|` in base `|x|`, where `x` is the
+    /// BLS parameter, such that `self = k_0 + k_1 |x| + k_2 |x|^2 + k_3 |x|^3`.
+    ///
+    /// Since the endomorphism `ψ` acts on $\mathbb{G}_2$ as multiplication by `x`, these
+    /// are the sub-scalars used by [`G2Projective::mul_glv`](crate::G2Projective::mul_glv).
+    /// The digits are computed in constant time by multiplying with a precomputed
+    /// reciprocal of `|x|` rather than with hardware division.
+    pub fn decompose_glv(&self) -> [u64; 4] {
+        let mut limbs = self.to_raw();
+        let mut digits = [0u64; 4];
+        // q < |x|^4, so the quotient after three divisions is the last digit
+        for digit in digits.iter_mut().take(3) {
+            let mut rem = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                (*limb, rem) = div_rem_bls_x(rem, *limb);
+            }
+            *digit = rem;
+        }
+        digits[3] = limbs[0];
+        digits
+    }
+
+    /// Left shift `self` by `count`, returning the result.
+    pub fn shl(&self, count: usize) -> Self {
+        let mut out = blst_fr::default();
+
+        unsafe { blst_fr_lshift(&mut out, &self.0, count) };
+
+        Scalar(out)
     }
 
     /// Right shift `self` by `count`, returning the result.
@@ -1130,6 +2309,52 @@ impl Scalar {
         unsafe { blst_fr_sqr(&mut self.0, &self.0) };
     }
 
+    /// Calculates the `cube` of this element.
+    pub fn cube(&self) -> Self {
+        self.square() * self
+    }
+
+    /// Calculates the `cube` of this element in place.
+    #[inline]
+    pub fn cube_assign(&mut self) {
+        *self = self.cube();
+    }
+
+    /// Computes `self * b + c`.
+    #[inline]
+    pub fn mul_add(&self, b: &Scalar, c: &Scalar) -> Self {
+        let mut out = blst_fr::default();
+        unsafe {
+            blst_fr_mul(&mut out, &self.0, &b.0);
+            blst_fr_add(&mut out, &out, &c.0);
+        }
+        Scalar(out)
+    }
+
+    /// Computes `self * b - c`.
+    #[inline]
+    pub fn mul_sub(&self, b: &Scalar, c: &Scalar) -> Self {
+        let mut out = blst_fr::default();
+        unsafe {
+            blst_fr_mul(&mut out, &self.0, &b.0);
+            blst_fr_sub(&mut out, &out, &c.0);
+        }
+        Scalar(out)
+    }
+
+    /// Raises this element to the power of a small integer `exp` by square and
+    /// multiply. This is variable time with respect to `exp`.
+    pub fn pow_small(&self, exp: u8) -> Self {
+        let mut res = Self::ONE;
+        for i in (0..(8 - exp.leading_zeros())).rev() {
+            res.square_assign();
+            if (exp >> i) & 1 == 1 {
+                res *= self;
+            }
+        }
+        res
+    }
+
     /// Converts a 512-bit little endian integer into
     /// a `Scalar` by reducing by the modulus.
     pub fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar {
@@ -1145,6 +2370,59 @@ impl Scalar {
         ])
     }
 
+    /// Interprets `bytes` as a big-endian integer of any length and
+    /// reduces it modulo `q`.
+    pub fn reduce_bytes_be(bytes: &[u8]) -> Scalar {
+        // Generalizes the trick used in `from_u512`: the `i`-th 256-bit chunk counted
+        // from the least significant end is worth `d_i * 2^(256 * i)`, which is obtained
+        // by a Montgomery multiplication of the raw limbs with `R^(i + 2)`.
+        let mut acc = Scalar::ZERO;
+        let mut factor = R2;
+        for chunk in bytes.rchunks(Self::BYTES) {
+            let mut le_bytes = [0u8; Self::BYTES];
+            le_bytes[Self::BYTES - chunk.len()..].copy_from_slice(chunk);
+            le_bytes.reverse();
+            let d = Scalar(blst_fr {
+                l: u64s_from_bytes(&le_bytes),
+            });
+            acc += d * factor;
+            factor *= R2;
+        }
+        acc
+    }
+
+    /// Interprets `digits` as a little-endian base-`2^64` integer of any length and
+    /// reduces it modulo `q`.
+    pub fn from_le_u64_digits(digits: &[u64]) -> Scalar {
+        // Same folding as `reduce_bytes_be`, the `i`-th 256-bit chunk is multiplied
+        // by `R^(i + 2)`.
+        let mut acc = Scalar::ZERO;
+        let mut factor = R2;
+        for chunk in digits.chunks(4) {
+            let mut l = [0u64; 4];
+            l[..chunk.len()].copy_from_slice(chunk);
+            acc += Scalar(blst_fr { l }) * factor;
+            factor *= R2;
+        }
+        acc
+    }
+
+    /// Interprets `bytes` as a 768-bit big-endian integer and reduces it modulo `q`.
+    pub fn from_bytes_mod_order_wide_96(bytes: &[u8; 96]) -> Scalar {
+        // As in `from_u512`, the three 256-bit digits are worth `d_0`, `d_1 * 2^256`
+        // and `d_2 * 2^512`, which Montgomery multiplication by `R^2`, `R^3` and `R^4`
+        // converts into Montgomery form.
+        let digit = |i: usize| {
+            let start = 64 - 32 * i;
+            let mut le_bytes = <[u8; 32]>::try_from(&bytes[start..start + 32]).unwrap();
+            le_bytes.reverse();
+            Scalar(blst_fr {
+                l: u64s_from_bytes(&le_bytes),
+            })
+        };
+        digit(0) * R2 + digit(1) * R3 + digit(2) * R4
+    }
+
     /// Read from output of a KDF
     pub fn from_okm(bytes: &[u8; 48]) -> Scalar {
         const F_2_192: Scalar = Scalar(blst_fr {
@@ -1174,6 +2452,29 @@ impl Scalar {
         (d0 * R2) * F_2_192 + d1 * R2
     }
 
+    /// Same as [`Scalar::from_okm`], but interprets `bytes` as a little-endian
+    /// integer.
+    pub fn from_okm_le(bytes: &[u8; 48]) -> Scalar {
+        let mut be_bytes = *bytes;
+        be_bytes.reverse();
+        Self::from_okm(&be_bytes)
+    }
+
+    /// Converts each consecutive 48-byte chunk of `okm` into a `Scalar` with
+    /// [`Scalar::from_okm`], failing if `okm.len()` is not a multiple of 48.
+    pub fn batch_from_okm(okm: &[u8]) -> Result<Vec<Scalar>, LengthError> {
+        let chunks = okm.chunks_exact(48);
+        if !chunks.remainder().is_empty() {
+            return Err(LengthError {
+                chunk_size: 48,
+                found: okm.len(),
+            });
+        }
+        Ok(chunks
+            .map(|chunk| Scalar::from_okm(<&[u8; 48]>::try_from(chunk).unwrap()))
+            .collect())
+    }
+
     fn from_u512(limbs: [u64; 8]) -> Scalar {
         // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
         // with the higher bits multiplied by 2^256. Thus, we perform two reductions
@@ -1198,6 +2499,17 @@ impl Scalar {
         d0 * R2 + d1 * R3
     }
 
+    /// Reduces the 257-bit integer `limbs + carry * 2^256`, with `limbs` in little
+    /// endian, modulo `q`.
+    ///
+    /// This is useful when adding field-sized integers without reduction.
+    pub fn reduce_256_with_carry(limbs: [u64; 4], carry: bool) -> Scalar {
+        // As in `from_u512`, multiplying by R^2 converts any 256-bit number into
+        // Montgomery form. The carry is worth 2^256, which is `R2` as a field element.
+        let d0 = Scalar(blst_fr { l: limbs }) * R2;
+        Self::conditional_select(&d0, &(d0 + R2), Choice::from(carry as u8))
+    }
+
     #[cfg(feature = "hashing")]
     pub fn hash<X>(msg: &[u8], dst: &[u8]) -> Self
     where
@@ -1211,6 +2523,63 @@ impl Scalar {
         expander.fill_bytes(&mut out);
         Scalar::from_okm(&out)
     }
+
+    #[cfg(feature = "hashing")]
+    /// Use `expand_message_xmd` with SHA-256 to map a value to a `Scalar`,
+    /// see [`Scalar::hash`].
+    pub fn hash_xmd_sha256(msg: &[u8], dst: &[u8]) -> Self {
+        Self::hash::<elliptic_curve::hash2curve::ExpandMsgXmd<sha2::Sha256>>(msg, dst)
+    }
+
+    #[cfg(feature = "hashing")]
+    /// Use `expand_message_xof` with SHAKE128 to map a value to a `Scalar`,
+    /// see [`Scalar::hash`].
+    pub fn hash_xof_shake128(msg: &[u8], dst: &[u8]) -> Self {
+        Self::hash::<elliptic_curve::hash2curve::ExpandMsgXof<sha3::Shake128>>(msg, dst)
+    }
+
+    #[cfg(feature = "hashing")]
+    /// Deterministically derives a `Scalar` from `seed` and `domain`.
+    ///
+    /// The derivation is fixed as 48 bytes of `expand_message_xmd` with SHA-256,
+    /// using `domain` as the DST, reduced with [`Scalar::from_okm`]. This is the
+    /// same as [`Scalar::hash_xmd_sha256`] and will not change.
+    pub fn derive(seed: &[u8], domain: &[u8]) -> Self {
+        Self::hash_xmd_sha256(seed, domain)
+    }
+
+    #[cfg(feature = "hashing")]
+    /// The domain separation tag used by [`Scalar::from_transcript`].
+    pub const TRANSCRIPT_DST: &'static [u8] = b"BLSTRS_PLUS_TRANSCRIPT_CHALLENGE_XMD:SHA-256_";
+
+    #[cfg(feature = "hashing")]
+    /// Computes a Fiat-Shamir challenge from `transcript`.
+    ///
+    /// This is 48 bytes of `expand_message_xmd` with SHA-256 and the fixed DST
+    /// [`Scalar::TRANSCRIPT_DST`], reduced with [`Scalar::from_okm`], so the
+    /// challenge is uniformly distributed.
+    pub fn from_transcript(transcript: &[u8]) -> Self {
+        Self::hash_xmd_sha256(transcript, Self::TRANSCRIPT_DST)
+    }
+
+    #[cfg(feature = "lossy-conversions")]
+    /// Converts a nonnegative integer valued `f64` less than `2^53` into a `Scalar`.
+    ///
+    /// Fails on negative, fractional, infinite, NaN or larger inputs, which can't
+    /// be represented exactly.
+    pub fn try_from_f64(x: f64) -> Result<Self, ConversionError> {
+        if !x.is_finite() {
+            Err(ConversionError::NotFinite)
+        } else if x.is_sign_negative() && x != 0.0 {
+            Err(ConversionError::Negative)
+        } else if x.fract() != 0.0 {
+            Err(ConversionError::NotAnInteger)
+        } else if x >= (1u64 << 53) as f64 {
+            Err(ConversionError::OutOfRange)
+        } else {
+            Ok(Self::from(x as u64))
+        }
+    }
 }
 
 #[cfg(target_pointer_width = "32")]
@@ -1228,6 +2597,41 @@ fn raw_scalar_to_32bit_le_array(scalar: &Scalar, arr: &mut [u32]) {
     }
 }
 
+/// A table of `base^(2^i)` for `i` in `0..255`, for raising the same base to
+/// many exponents.
+#[derive(Clone, Debug)]
+pub struct ScalarPowTable {
+    powers: Vec<Scalar>,
+}
+
+impl ScalarPowTable {
+    /// Precompute the table for `base`.
+    pub fn new(base: &Scalar) -> Self {
+        let mut powers = Vec::with_capacity(Scalar::NUM_BITS as usize);
+        let mut power = *base;
+        for _ in 0..Scalar::NUM_BITS {
+            powers.push(power);
+            power.square_assign();
+        }
+        Self { powers }
+    }
+
+    /// Raises the base to the power of the canonical integer representation of
+    /// `exp`, with one multiplication per set bit and no squarings. This is
+    /// variable time with respect to `exp`.
+    pub fn pow(&self, exp: &Scalar) -> Scalar {
+        let mut res = Scalar::ONE;
+        for (i, limb) in exp.to_raw().iter().enumerate() {
+            for j in 0..64 {
+                if (limb >> j) & 1 == 1 {
+                    res *= &self.powers[64 * i + j];
+                }
+            }
+        }
+        res
+    }
+}
+
 #[cfg(feature = "gpu")]
 impl ec_gpu::GpuName for Scalar {
     fn name() -> String {
@@ -1250,6 +2654,39 @@ impl ec_gpu::GpuField for Scalar {
     }
 }
 
+#[cfg(feature = "gpu")]
+impl Scalar {
+    /// Flattens `scalars` into the little-endian `u32` limbs of their Montgomery
+    /// representation, the same layout as [`ec_gpu::GpuField::one`], for upload to
+    /// device memory.
+    pub fn to_gpu_buffer(scalars: &[Scalar]) -> Vec<u32> {
+        scalars
+            .iter()
+            .flat_map(|s| crate::u64_to_u32(&s.0.l[..]))
+            .collect()
+    }
+
+    /// The inverse of [`Scalar::to_gpu_buffer`], returns `None` if the length of
+    /// `buffer` is not a multiple of 8 or an element is not less than the modulus.
+    pub fn from_gpu_buffer(buffer: &[u32]) -> Option<Vec<Scalar>> {
+        let chunks = buffer.chunks_exact(8);
+        if !chunks.remainder().is_empty() {
+            return None;
+        }
+        chunks
+            .map(|c| {
+                let l = [
+                    c[0] as u64 | ((c[1] as u64) << 32),
+                    c[2] as u64 | ((c[3] as u64) << 32),
+                    c[4] as u64 | ((c[5] as u64) << 32),
+                    c[6] as u64 | ((c[7] as u64) << 32),
+                ];
+                is_valid(&l).then_some(Scalar(blst_fr { l }))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2491,6 +3928,2007 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn test_from_okm_le() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let mut okm = [0u8; 48];
+            rng.fill_bytes(&mut okm);
+            let mut reversed = okm;
+            reversed.reverse();
+            assert_eq!(Scalar::from_okm_le(&okm), Scalar::from_okm(&reversed));
+            assert_eq!(
+                Scalar::from_okm_le(&okm),
+                Scalar::from_bytes_mod_order_wide_96(&{
+                    let mut wide = [0u8; 96];
+                    wide[48..].copy_from_slice(&reversed);
+                    wide
+                })
+            );
+        }
+
+        // Small values are unchanged by the reduction
+        let mut okm = [0u8; 48];
+        okm[0] = 42;
+        assert_eq!(Scalar::from_okm_le(&okm), Scalar::from(42u64));
+        okm = [0xff; 48];
+        assert_eq!(Scalar::from_okm_le(&okm), Scalar::from_okm(&okm));
+    }
+
+    #[test]
+    fn test_conditional_negate() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+
+            let mut b = a;
+            b.conditional_negate(Choice::from(0u8));
+            assert_eq!(a, b);
+
+            b.conditional_negate(Choice::from(1u8));
+            assert_eq!(-a, b);
+        }
+
+        let mut zero = Scalar::ZERO;
+        zero.conditional_negate(Choice::from(1u8));
+        assert_eq!(Scalar::ZERO, zero);
+
+        let values = [
+            Scalar::ONE,
+            Scalar::from(2u64),
+            Scalar::ZERO,
+            Scalar::from(4u64),
+        ];
+        let choices = [
+            Choice::from(1u8),
+            Choice::from(0u8),
+            Choice::from(1u8),
+            Choice::from(0u8),
+        ];
+        let mut negated = values;
+        Scalar::conditional_negate_slice(&mut negated, &choices);
+        assert_eq!(
+            negated,
+            [
+                -Scalar::ONE,
+                Scalar::from(2u64),
+                Scalar::ZERO,
+                Scalar::from(4u64)
+            ]
+        );
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_biguint() {
+        use num_bigint::BigUint;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for a in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+        ] {
+            let n = BigUint::from(a);
+            assert_eq!(n, BigUint::from_bytes_be(&a.to_be_bytes()));
+            assert_eq!(Scalar::try_from(&n).unwrap(), a);
+        }
+
+        let modulus = BigUint::from_bytes_le(&MODULUS_REPR);
+        assert_eq!(Scalar::try_from(&modulus), Err(ScalarError::NotCanonical));
+        assert_eq!(
+            Scalar::try_from(&(modulus << 1)),
+            Err(ScalarError::NotCanonical)
+        );
+    }
+
+    #[test]
+    fn test_signed_i128() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::from_i128(-5), -Scalar::from(5u64));
+        assert_eq!(Scalar::from_i128(5), Scalar::from(5u64));
+        assert_eq!(Scalar::from_i128(0), Scalar::ZERO);
+        assert_eq!(Scalar::from_i128(-1), -Scalar::ONE);
+
+        for x in [
+            0,
+            1,
+            -1,
+            5,
+            -5,
+            i64::MIN as i128,
+            u64::MAX as i128,
+            i128::MAX,
+            i128::MIN,
+            rng.next_u64() as i128 * rng.next_u32() as i128,
+            -(rng.next_u64() as i128),
+        ] {
+            assert_eq!(Scalar::from_i128(x).to_signed_i128(), Some(x));
+        }
+
+        // Just outside of the range on both sides
+        assert_eq!(Scalar::from(1u128 << 127).to_signed_i128(), None);
+        assert_eq!(
+            (Scalar::from_i128(i128::MIN) - Scalar::ONE).to_signed_i128(),
+            None
+        );
+        assert_eq!(Scalar::random(&mut rng).to_signed_i128(), None);
+    }
+
+    #[test]
+    fn test_jacobi() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for (a, n, expected) in [
+            (1001u64, 9907u64, -1i8),
+            (19, 45, 1),
+            (8, 21, -1),
+            (5, 21, 1),
+            (30, 7, 1),
+            (2, 15, 1),
+            (7, 15, -1),
+            (3, 9, 0),
+            (0, 9, 0),
+            (0, 1, 1),
+            (5, 1, 1),
+            (12345678901234567890, 1000000007, -1),
+        ] {
+            assert_eq!(Scalar::jacobi(&Scalar::from(a), &[n, 0, 0, 0]), expected);
+        }
+
+        let mut values = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        values.extend((0..50).map(|_| Scalar::random(&mut rng)));
+        for a in values {
+            assert_eq!(Scalar::jacobi(&a, &MODULUS), a.legendre());
+        }
+
+        // (2^127 - 1) * (2^89 - 1) and 2^255 - 19
+        let n = [0x1, 0x7fff_ffff_fe00_0000, 0xffff_ffff_ffff_ffff, 0xff_ffff];
+        let p = [
+            0xffff_ffff_ffff_ffed,
+            0xffff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+            0x7fff_ffff_ffff_ffff,
+        ];
+        let a =
+            Scalar::from_be_hex("1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef")
+                .unwrap();
+        assert_eq!(Scalar::jacobi(&a, &n), -1);
+        assert_eq!(Scalar::jacobi(&a, &p), 1);
+        assert_eq!(Scalar::jacobi(&-Scalar::ONE, &n), 1);
+        assert_eq!(Scalar::jacobi(&Scalar::from(6u64), &n), 1);
+        assert_eq!(Scalar::jacobi(&Scalar::from(6u64), &p), -1);
+        assert_eq!(Scalar::jacobi(&Scalar::from(7u64), &n), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jacobi_even() {
+        Scalar::jacobi(&Scalar::ONE, &[2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_batch_legendre() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut values = vec![Scalar::ZERO, Scalar::ONE];
+        for _ in 0..20 {
+            let a = Scalar::random(&mut rng).square();
+            values.push(a);
+            values.push(a * Scalar::MULTIPLICATIVE_GENERATOR);
+            values.push(Scalar::random(&mut rng));
+        }
+
+        let symbols = Scalar::batch_legendre(&values);
+        assert_eq!(symbols.len(), values.len());
+        for (v, s) in values.iter().zip(symbols.iter()) {
+            assert_eq!(v.legendre(), *s);
+        }
+        assert_eq!(symbols[..4], [0, 1, 1, -1]);
+        assert!(Scalar::batch_legendre(&[]).is_empty());
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_gpu_buffer() {
+        use ec_gpu::GpuField;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::to_gpu_buffer(&[Scalar::ONE]), Scalar::one());
+        assert_eq!(Scalar::to_gpu_buffer(&[Scalar::ZERO]), vec![0u32; 8]);
+
+        let scalars: Vec<Scalar> = (0..20).map(|_| Scalar::random(&mut rng)).collect();
+        let buffer = Scalar::to_gpu_buffer(&scalars);
+        assert_eq!(buffer.len(), 8 * scalars.len());
+        assert_eq!(Scalar::from_gpu_buffer(&buffer), Some(scalars));
+        assert_eq!(Scalar::from_gpu_buffer(&[]), Some(vec![]));
+
+        assert_eq!(Scalar::from_gpu_buffer(&buffer[..7]), None);
+        assert_eq!(Scalar::from_gpu_buffer(&Scalar::modulus()), None);
+    }
+
+    #[test]
+    fn test_from_le_u64_digits() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::from_le_u64_digits(&[]), Scalar::ZERO);
+        assert_eq!(Scalar::from_le_u64_digits(&[7]), Scalar::from(7u64));
+        assert_eq!(Scalar::from_le_u64_digits(&MODULUS), Scalar::ZERO);
+
+        for _ in 0..20 {
+            let mut digits = [0u64; 12];
+            for digit in digits.iter_mut() {
+                *digit = rng.next_u64();
+            }
+
+            assert_eq!(
+                Scalar::from_le_u64_digits(&digits[..4]),
+                Scalar::from_raw_reduce(<[u64; 4]>::try_from(&digits[..4]).unwrap())
+            );
+            assert_eq!(
+                Scalar::from_le_u64_digits(&digits[..8]),
+                Scalar::from_u512(<[u64; 8]>::try_from(&digits[..8]).unwrap())
+            );
+
+            let mut be_bytes = [0u8; 96];
+            for (chunk, digit) in be_bytes.rchunks_exact_mut(8).zip(digits.iter()) {
+                chunk.copy_from_slice(&digit.to_be_bytes());
+            }
+            assert_eq!(
+                Scalar::from_le_u64_digits(&digits),
+                Scalar::from_bytes_mod_order_wide_96(&be_bytes)
+            );
+            assert_eq!(
+                Scalar::from_le_u64_digits(&digits[..7]),
+                Scalar::reduce_bytes_be(&be_bytes[96 - 56..])
+            );
+        }
+
+        // 2^768 - 1 mod q
+        assert_eq!(
+            Scalar::from_le_u64_digits(&[u64::MAX; 12]),
+            Scalar::from_be_hex("6e2a5bb9c8db33e973d13c71c7b5f4181b3e0d188cf06990c62c1807439b73ae")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_mod_order_wide_96() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..20 {
+            let a = Scalar::random(&mut rng);
+            let mut bytes = [0u8; 96];
+            bytes[64..].copy_from_slice(&a.to_be_bytes());
+            assert_eq!(Scalar::from_bytes_mod_order_wide_96(&bytes), a);
+
+            rng.fill_bytes(&mut bytes);
+            assert_eq!(
+                Scalar::from_bytes_mod_order_wide_96(&bytes),
+                Scalar::reduce_bytes_be(&bytes)
+            );
+        }
+
+        // 2^768 - 1 mod q
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_96(&[0xff; 96]),
+            Scalar::from_be_hex("6e2a5bb9c8db33e973d13c71c7b5f4181b3e0d188cf06990c62c1807439b73ae")
+                .unwrap()
+        );
+
+        let mut bytes = [0u8; 96];
+        bytes[31] = 1;
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_96(&bytes),
+            Scalar::from(2u64).pow_vartime([512])
+        );
+    }
+
+    #[test]
+    fn test_add_sub_u64() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..20 {
+            let x = Scalar::random(&mut rng);
+            for c in [0, 1, 5, u32::MAX as u64, u64::MAX, rng.next_u64()] {
+                assert_eq!(x.add_u64(c), x + Scalar::from(c));
+                assert_eq!(x.sub_u64(c), x - Scalar::from(c));
+                assert_eq!(x + c, x + Scalar::from(c));
+                assert_eq!(x - c, x - Scalar::from(c));
+                assert_eq!(x.add_u64(c).sub_u64(c), x);
+            }
+        }
+
+        assert_eq!(Scalar::ZERO.sub_u64(1), -Scalar::ONE);
+        assert_eq!(Scalar::from(3u64) - 5, -Scalar::from(2u64));
+        assert_eq!((-Scalar::ONE).add_u64(1), Scalar::ZERO);
+    }
+
+    #[test]
+    fn test_reduce_256_with_carry() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let two_pow_256 = Scalar::from(2u64).pow_vartime([256]);
+
+        assert_eq!(Scalar::reduce_256_with_carry(MODULUS, false), Scalar::ZERO);
+        assert_eq!(Scalar::reduce_256_with_carry(MODULUS, true), two_pow_256);
+        assert_eq!(Scalar::reduce_256_with_carry([0; 4], true), two_pow_256);
+        assert_eq!(
+            Scalar::reduce_256_with_carry([u64::MAX; 4], false),
+            two_pow_256 - Scalar::ONE
+        );
+
+        for _ in 0..100 {
+            let mut limbs = [0u64; 4];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            let a = Scalar::reduce_256_with_carry(limbs, false);
+            assert_eq!(
+                a,
+                Scalar::from_u512([limbs[0], limbs[1], limbs[2], limbs[3], 0, 0, 0, 0])
+            );
+            assert_eq!(Scalar::reduce_256_with_carry(limbs, true), a + two_pow_256);
+        }
+    }
+
+    #[test]
+    fn test_pow_vartime_scalar() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // q - 2
+        let q_minus_two = -Scalar::from(2u64);
+        for _ in 0..20 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            assert_eq!(a.pow_vartime_scalar(&b), a.pow_vartime(b.to_raw()));
+            assert_eq!(a.pow_vartime_scalar(&q_minus_two), a.invert().unwrap());
+            assert_eq!(a.pow_vartime_scalar(&Scalar::ZERO), Scalar::ONE);
+            assert_eq!(a.pow_vartime_scalar(&Scalar::ONE), a);
+        }
+        assert_eq!(Scalar::ZERO.pow_vartime_scalar(&q_minus_two), Scalar::ZERO);
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_derive() {
+        let seed = (0u8..32).collect::<Vec<_>>();
+        let expected =
+            Scalar::from_be_hex("17dd0424076fe5dbbfb3d9ecff00c72bfe0d839687724ceea7c479e0e63115f8")
+                .unwrap();
+        assert_eq!(Scalar::derive(&seed, b"BLSTRS_DERIVE_TEST"), expected);
+        assert_ne!(Scalar::derive(&seed, b"BLSTRS_DERIVE_TEST_"), expected);
+        assert_ne!(Scalar::derive(&seed[1..], b"BLSTRS_DERIVE_TEST"), expected);
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_from_transcript() {
+        let expected =
+            Scalar::from_be_hex("33d39b9d5dd20993332c581d0d4c7ff3924c04f58146cb2566601f7275cff1ff")
+                .unwrap();
+        assert_eq!(Scalar::from_transcript(b"test transcript"), expected);
+        assert_eq!(
+            Scalar::from_transcript(b"test transcript"),
+            Scalar::hash_xmd_sha256(b"test transcript", Scalar::TRANSCRIPT_DST)
+        );
+
+        let challenges = [&b""[..], b"a", b"b", b"ab", b"test transcript!"]
+            .iter()
+            .map(|t| Scalar::from_transcript(t))
+            .collect::<Vec<_>>();
+        for (i, c) in challenges.iter().enumerate() {
+            assert_ne!(*c, expected);
+            for d in &challenges[i + 1..] {
+                assert_ne!(c, d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_table() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let base = Scalar::random(&mut rng);
+        let table = ScalarPowTable::new(&base);
+        assert_eq!(table.pow(&Scalar::ZERO), Scalar::ONE);
+        assert_eq!(table.pow(&Scalar::ONE), base);
+        assert_eq!(table.pow(&-Scalar::ONE), Scalar::ONE);
+        for _ in 0..20 {
+            let e = Scalar::random(&mut rng);
+            assert_eq!(table.pow(&e), base.pow_vartime(e.to_raw()));
+        }
+
+        let table = ScalarPowTable::new(&Scalar::ZERO);
+        assert_eq!(table.pow(&Scalar::ZERO), Scalar::ONE);
+        assert_eq!(table.pow(&Scalar::random(&mut rng)), Scalar::ZERO);
+    }
+
+    #[test]
+    fn test_invert_or_zero() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::ZERO.invert_or_zero(), Scalar::ZERO);
+        assert_eq!(Scalar::ONE.invert_or_zero(), Scalar::ONE);
+        assert_eq!((-Scalar::ONE).invert_or_zero(), -Scalar::ONE);
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            assert_eq!(a.invert_or_zero(), a.invert().unwrap());
+            assert_eq!(a * a.invert_or_zero(), Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn test_pow_vartime_be_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let a = Scalar::random(&mut rng);
+
+            let mut limbs = [0u64; 4];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            let mut be_bytes = [0u8; 32];
+            for (chunk, limb) in be_bytes.rchunks_exact_mut(8).zip(limbs.iter()) {
+                chunk.copy_from_slice(&limb.to_be_bytes());
+            }
+            assert_eq!(a.pow_vartime_be_bytes(&be_bytes), a.pow_vartime(limbs));
+
+            assert_eq!(a.pow_vartime_be_bytes(&[]), Scalar::ONE);
+            assert_eq!(a.pow_vartime_be_bytes(&[0]), Scalar::ONE);
+            assert_eq!(a.pow_vartime_be_bytes(&[0; 40]), Scalar::ONE);
+            assert_eq!(a.pow_vartime_be_bytes(&[0, 0, 3]), a.cube());
+
+            // a^(q - 1) = 1, so a^(2^256 + q - 1) = a^(2^256)
+            let mut exp = [0u8; 33];
+            exp[1..].copy_from_slice(&(-Scalar::ONE).to_be_bytes());
+            assert_eq!(a.pow_vartime_be_bytes(&exp), Scalar::ONE);
+            exp[0] = 1;
+            assert_eq!(a.pow_vartime_be_bytes(&exp), a.pow_vartime([0, 0, 0, 0, 1]));
+        }
+    }
+
+    #[test]
+    fn test_random_bits() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::random_bits(&mut rng, 0), Scalar::ZERO);
+        for bits in [1, 7, 8, 9, 64, 128, 200, 254] {
+            let mut max = 0;
+            for _ in 0..100 {
+                let a = Scalar::random_bits(&mut rng, bits);
+                assert!(a.num_bits() <= bits);
+                max = max.max(a.num_bits());
+            }
+            assert_eq!(max, bits);
+        }
+        for bits in [255, 256, 1000] {
+            for _ in 0..100 {
+                let a = Scalar::random_bits(&mut rng, bits);
+                assert!(bool::from(
+                    Scalar::from_be_bytes(&a.to_be_bytes()).is_some()
+                ));
+                assert!(a.num_bits() <= 255);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal_string() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::ZERO.to_decimal_string(), "0");
+        assert_eq!(Scalar::ONE.to_decimal_string(), "1");
+        assert_eq!(
+            Scalar::from(u64::MAX).to_decimal_string(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            (-Scalar::ONE).to_decimal_string(),
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+        );
+        assert_eq!(
+            Scalar::from(10_000_000_000_000_000_000u128).to_decimal_string(),
+            "10000000000000000000"
+        );
+
+        for a in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..100).map(|_| Scalar::random(&mut rng)))
+        {
+            assert_eq!(Scalar::from_str(&a.to_decimal_string()), Ok(a));
+            assert_eq!(Scalar::from_str(&format!("0x{:x}", a)), Ok(a));
+        }
+
+        assert_eq!(Scalar::from_str("0x0"), Ok(Scalar::ZERO));
+        assert_eq!(Scalar::from_str("0xFF"), Ok(Scalar::from(255u64)));
+        assert_eq!(Scalar::from_str("007"), Ok(Scalar::from(7u64)));
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(Scalar::from_str(""), Err(ParseScalarError::Empty));
+        assert_eq!(Scalar::from_str("0x"), Err(ParseScalarError::Empty));
+        assert_eq!(
+            Scalar::from_str("12a4"),
+            Err(ParseScalarError::InvalidDigit { index: 2 })
+        );
+        assert_eq!(
+            Scalar::from_str("-1"),
+            Err(ParseScalarError::InvalidDigit { index: 0 })
+        );
+        assert_eq!(
+            Scalar::from_str("0x1g"),
+            Err(ParseScalarError::InvalidDigit { index: 3 })
+        );
+
+        // The modulus and above
+        assert_eq!(
+            Scalar::from_str(
+                "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+            ),
+            Err(ParseScalarError::NotInField)
+        );
+        assert_eq!(
+            Scalar::from_str(
+                "52435875175126190479447740508185965837690552500527637822603658699938581184514"
+            ),
+            Err(ParseScalarError::NotInField)
+        );
+        assert_eq!(
+            Scalar::from_str(&"9".repeat(100)),
+            Err(ParseScalarError::NotInField)
+        );
+        assert_eq!(
+            Scalar::from_str("0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"),
+            Err(ParseScalarError::NotInField)
+        );
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let bits = Scalar::ONE.bits_le();
+        assert_eq!(bits.len(), 256);
+        assert!(bits[0]);
+        assert!(bits[1..].iter().all(|b| !b));
+
+        let bits = Scalar::from(u64::MAX).bits_le();
+        assert!(bits[..64].iter().all(|b| *b));
+        assert!(bits[64..].iter().all(|b| !b));
+
+        assert!(Scalar::ZERO.bits_le().iter().all(|b| !b));
+
+        for a in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..100).map(|_| Scalar::random(&mut rng)))
+        {
+            let be = a.bits_be();
+            assert_eq!(be.len(), 256);
+            assert!(!be[0]);
+
+            let le = a.bits_le();
+            let expected: Vec<bool> = a.to_le_bits().iter().map(|b| *b).collect();
+            assert_eq!(le, expected[..256]);
+            assert!(le.iter().rev().eq(be.iter()));
+        }
+    }
+
+    #[test]
+    fn test_batch_invert_scratch() {
+        use ff::BatchInvert;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let values: Vec<Scalar> = (0..20).map(|_| Scalar::random(&mut rng)).collect();
+
+        let mut expected = values.clone();
+        expected.iter_mut().batch_invert();
+
+        let mut actual = values.clone();
+        let mut scratch = [Scalar::ZERO; 20];
+        assert!(bool::from(Scalar::batch_invert_scratch(
+            &mut actual,
+            &mut scratch
+        )));
+        assert_eq!(actual, expected);
+        for (a, v) in actual.iter().zip(values.iter()) {
+            assert_eq!(*a, v.invert().unwrap());
+        }
+
+        let mut with_zeros = values;
+        with_zeros[0] = Scalar::ZERO;
+        with_zeros[7] = Scalar::ZERO;
+        let mut expected = with_zeros.clone();
+        expected.iter_mut().batch_invert();
+        let mut actual = with_zeros;
+        assert!(!bool::from(Scalar::batch_invert_scratch(
+            &mut actual,
+            &mut scratch
+        )));
+        assert_eq!(actual, expected);
+        assert_eq!(actual[0], Scalar::ZERO);
+        assert_eq!(actual[7], Scalar::ZERO);
+
+        assert!(bool::from(Scalar::batch_invert_scratch(&mut [], &mut [])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_invert_scratch_length_mismatch() {
+        let mut values = [Scalar::ONE; 3];
+        let mut scratch = [Scalar::ZERO; 2];
+        Scalar::batch_invert_scratch(&mut values, &mut scratch);
+    }
+
+    #[test]
+    fn test_sqrt_vartime() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::ZERO.sqrt_vartime().unwrap(), Scalar::ZERO);
+        assert_eq!(Scalar::ONE.sqrt_vartime().unwrap().square(), Scalar::ONE);
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng).square();
+            let b = a.sqrt_vartime().unwrap();
+            assert_eq!(b.square(), a);
+            assert_eq!(b, a.sqrt().unwrap());
+
+            // The multiplicative generator is a non-residue, so is its product with a
+            // non-zero square.
+            let n = a * Scalar::MULTIPLICATIVE_GENERATOR;
+            assert!(bool::from(n.sqrt_vartime().is_none()));
+            assert!(bool::from(n.sqrt().is_none()));
+        }
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_hash_xmd_sha256() {
+        use elliptic_curve::hash2curve::ExpandMsgXmd;
+
+        // hash_to_scalar fixture from the BLS12-381-SHA-256 ciphersuite of
+        // draft-irtf-cfrg-bbs-signatures, which is RFC 9380 hash_to_field with L = 48
+        let msg = hex::decode("9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02")
+            .unwrap();
+        let dst = hex::decode(
+            "4242535f424c53313233383147315f584d443a5348412d3235365f535357555f524f5f4832475f484d32535f4832535f",
+        )
+        .unwrap();
+        let expected =
+            Scalar::from_be_hex("0f90cbee27beb214e6545becb8404640d3612da5d6758dffeccd77ed7169807c")
+                .unwrap();
+        assert_eq!(Scalar::hash_xmd_sha256(&msg, &dst), expected);
+        assert_eq!(
+            Scalar::hash::<ExpandMsgXmd<sha2::Sha256>>(&msg, &dst),
+            expected
+        );
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_hash_xof_shake128() {
+        use elliptic_curve::hash2curve::ExpandMsgXof;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let a = Scalar::hash_xof_shake128(msg, DST);
+            assert_eq!(a, Scalar::hash::<ExpandMsgXof<sha3::Shake128>>(msg, DST));
+            assert_ne!(a, Scalar::hash_xmd_sha256(msg, DST));
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Scalar::MINUS_ONE, -Scalar::ONE);
+        assert_eq!(Scalar::MINUS_ONE, Scalar::ZERO - Scalar::ONE);
+        assert_eq!(Scalar::TWO, Scalar::from(2u64));
+        assert_eq!(Scalar::TWO, Scalar::ONE.double());
+
+        // 2 * (q - 1) / 2 + 1 = q
+        let half = Scalar::from_raw(Scalar::MODULUS_MINUS_ONE_DIV_TWO).unwrap();
+        assert_eq!(half * Scalar::TWO + Scalar::ONE, Scalar::ZERO);
+        assert!(!bool::from(half.is_high()));
+        assert!(bool::from((half + Scalar::ONE).is_high()));
+
+        // Euler's criterion
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..10 {
+            let a = Scalar::random(&mut rng);
+            let euler = a.pow_vartime(Scalar::MODULUS_MINUS_ONE_DIV_TWO);
+            match a.legendre() {
+                1 => assert_eq!(euler, Scalar::ONE),
+                -1 => assert_eq!(euler, Scalar::MINUS_ONE),
+                _ => assert_eq!(euler, Scalar::ZERO),
+            }
+            assert_eq!(
+                a.square().pow_vartime(Scalar::MODULUS_MINUS_ONE_DIV_TWO),
+                Scalar::ONE
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_integer() {
+        // `from_integer` interprets the limbs as an integer
+        assert_eq!(Scalar::from_integer([1, 0, 0, 0]).unwrap(), Scalar::ONE);
+        assert_eq!(
+            Scalar::from_integer([7, 0, 0, 0]).unwrap(),
+            Scalar::from(7u64)
+        );
+        assert!(bool::from(Scalar::from_integer(MODULUS).is_none()));
+
+        // `from_montgomery_limbs_unchecked` stores the limbs as the Montgomery form,
+        // so the integer 1 is stored as `R mod q`
+        assert_eq!(Scalar::from_montgomery_limbs_unchecked(R.0.l), Scalar::ONE);
+        assert_ne!(
+            Scalar::from_montgomery_limbs_unchecked([1, 0, 0, 0]),
+            Scalar::ONE
+        );
+        assert_eq!(
+            Scalar::from_montgomery_limbs_unchecked([1, 0, 0, 0]) * R2,
+            Scalar::ONE
+        );
+
+        let a = Scalar::from(0x1234_5678_9abc_def0u64);
+        assert_eq!(Scalar::from_integer(a.to_raw()).unwrap(), a);
+        assert_eq!(Scalar::from_montgomery_limbs_unchecked(a.0.l), a);
+    }
+
+    #[test]
+    fn test_random_vec() {
+        let seed = [
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ];
+
+        let a = Scalar::random_vec(XorShiftRng::from_seed(seed), 16);
+        assert_eq!(a.len(), 16);
+        assert_eq!(a, Scalar::random_vec(XorShiftRng::from_seed(seed), 16));
+        for (i, x) in a.iter().enumerate() {
+            assert!(a[i + 1..].iter().all(|y| y != x));
+        }
+        assert!(Scalar::random_vec(XorShiftRng::from_seed(seed), 0).is_empty());
+
+        let b = Scalar::random_nonzero_vec(XorShiftRng::from_seed(seed), 16);
+        assert_eq!(b.len(), 16);
+        assert_eq!(b, a);
+        assert!(b.iter().all(|x| !bool::from(x.is_zero())));
+
+        // An rng that only returns zeros for the first block
+        struct ZeroThen(usize, XorShiftRng);
+        impl RngCore for ZeroThen {
+            fn next_u32(&mut self) -> u32 {
+                self.1.next_u32()
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.1.next_u64()
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                if self.0 == 0 {
+                    dest.fill(0);
+                } else {
+                    self.1.fill_bytes(dest);
+                }
+                self.0 += 1;
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        let c = Scalar::random_nonzero_vec(ZeroThen(0, XorShiftRng::from_seed(seed)), 4);
+        assert_eq!(c.len(), 4);
+        assert!(c.iter().all(|x| !bool::from(x.is_zero())));
+        assert!(bool::from(
+            Scalar::random_vec(ZeroThen(0, XorShiftRng::from_seed(seed)), 4)[0].is_zero()
+        ));
+    }
+
+    #[test]
+    fn test_from_bits_le() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::from_bits_le(&[]).unwrap(), Scalar::ZERO);
+        assert_eq!(
+            Scalar::from_bits_le(&[true, false, true]).unwrap(),
+            Scalar::from(5u64)
+        );
+        for v in [0u64, 1, 2, 5, 0xff, 0x1234_5678, u64::MAX] {
+            let s = Scalar::from(v);
+            let bits = s.to_bits_le_fixed(64).unwrap();
+            assert_eq!(bits.len(), 64);
+            assert_eq!(Scalar::from_bits_le(&bits).unwrap(), s);
+        }
+        assert_eq!(
+            Scalar::from(5u64).to_bits_le_fixed(3),
+            Some(vec![true, false, true])
+        );
+        assert_eq!(Scalar::from(5u64).to_bits_le_fixed(2), None);
+        assert_eq!(Scalar::ZERO.to_bits_le_fixed(0), Some(vec![]));
+
+        for _ in 0..10 {
+            let s = Scalar::random(&mut rng);
+            let bits = s.to_bits_le_fixed(255).unwrap();
+            assert_eq!(Scalar::from_bits_le(&bits).unwrap(), s);
+            assert_eq!(s.to_bits_le_fixed(300).unwrap().len(), 300);
+        }
+
+        // q and q + 1 are not in the field
+        let mut modulus_bits = MODULUS_REPR
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .take(255)
+            .collect::<Vec<_>>();
+        assert!(bool::from(Scalar::from_bits_le(&modulus_bits).is_none()));
+        modulus_bits[0] = true;
+        assert!(bool::from(Scalar::from_bits_le(&modulus_bits).is_none()));
+        assert!(bool::from(Scalar::from_bits_le(&[true; 255]).is_none()));
+
+        // At most 255 bits are accepted
+        assert!(bool::from(Scalar::from_bits_le(&[false; 256]).is_none()));
+    }
+
+    #[test]
+    fn test_halve() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::ZERO.halve(), Scalar::ZERO);
+        assert_eq!(Scalar::TWO.halve(), Scalar::ONE);
+        assert_eq!(Scalar::ONE.halve(), <Scalar as PrimeField>::TWO_INV);
+        assert_eq!(Scalar::ONE.halve().double(), Scalar::ONE);
+        assert_eq!(
+            Scalar::from(7u64).halve(),
+            Scalar::from(7u64) * <Scalar as PrimeField>::TWO_INV
+        );
+        for _ in 0..100 {
+            let x = Scalar::random(&mut rng);
+            assert_eq!(x.double().halve(), x);
+            assert_eq!(x.halve().double(), x);
+            assert_eq!(x.halve(), x * <Scalar as PrimeField>::TWO_INV);
+
+            assert_eq!(x.conditional_halve(Choice::from(0)), x);
+            assert_eq!(x.conditional_halve(Choice::from(1)), x.halve());
+            assert_eq!(x.conditional_double(Choice::from(0)), x);
+            assert_eq!(x.conditional_double(Choice::from(1)), x.double());
+        }
+    }
+
+    #[test]
+    fn test_powers() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let x = Scalar::random(&mut rng);
+        assert!(x.powers(0).is_empty());
+        assert_eq!(x.powers(1), vec![Scalar::ONE]);
+        assert_eq!(
+            x.powers(5),
+            vec![Scalar::ONE, x, x * x, x * x * x, x * x * x * x]
+        );
+        for (i, p) in x.powers(20).iter().enumerate() {
+            assert_eq!(*p, x.pow_vartime([i as u64]));
+        }
+
+        let mut out = [Scalar::ZERO; 4];
+        Scalar::from(3u64).powers_into(&mut out);
+        assert_eq!(out, [1u64, 3, 9, 27].map(Scalar::from));
+        assert_eq!(
+            Scalar::ZERO.powers(3),
+            vec![Scalar::ONE, Scalar::ZERO, Scalar::ZERO]
+        );
+    }
+
+    #[test]
+    fn test_pow_signed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let a = Scalar::random(&mut rng);
+            assert_eq!(a.pow_signed(0).unwrap(), Scalar::ONE);
+            assert_eq!(a.pow_signed(1).unwrap(), a);
+            assert_eq!(a.pow_signed(-1).unwrap(), a.invert().unwrap());
+            assert_eq!(a.pow_signed(5).unwrap(), a.pow_vartime([5]));
+            assert_eq!(
+                a.pow_signed(-5).unwrap(),
+                a.invert().unwrap().pow_vartime([5])
+            );
+            assert_eq!(
+                a.pow_signed(-7).unwrap() * a.pow_signed(7).unwrap(),
+                Scalar::ONE
+            );
+            assert_eq!(
+                a.pow_signed(i64::MIN).unwrap(),
+                a.invert().unwrap().pow_vartime([1 << 63])
+            );
+        }
+
+        assert_eq!(Scalar::ZERO.pow_signed(0).unwrap(), Scalar::ONE);
+        assert_eq!(Scalar::ZERO.pow_signed(3).unwrap(), Scalar::ZERO);
+        assert!(bool::from(Scalar::ZERO.pow_signed(-1).is_none()));
+    }
+
+    #[test]
+    fn test_montgomery_limbs() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::ONE.to_montgomery_limbs(), R.0.l);
+        assert_eq!(Scalar::ZERO.to_montgomery_limbs(), [0; 4]);
+        for _ in 0..10 {
+            let a = Scalar::random(&mut rng);
+            let limbs = a.to_montgomery_limbs();
+            assert_eq!(Scalar::from_montgomery_limbs(limbs).unwrap(), a);
+            assert_ne!(limbs, a.to_raw());
+            assert_ne!(Scalar::from_raw(limbs).unwrap(), a);
+        }
+        assert!(bool::from(Scalar::from_montgomery_limbs(MODULUS).is_none()));
+        assert!(bool::from(
+            Scalar::from_montgomery_limbs([u64::MAX; 4]).is_none()
+        ));
+    }
+
+    #[cfg(feature = "lossy-conversions")]
+    #[test]
+    fn test_try_from_f64() {
+        assert_eq!(Scalar::try_from_f64(0.0), Ok(Scalar::ZERO));
+        assert_eq!(Scalar::try_from_f64(-0.0), Ok(Scalar::ZERO));
+        assert_eq!(Scalar::try_from_f64(1.0), Ok(Scalar::ONE));
+        assert_eq!(Scalar::try_from_f64(12345.0), Ok(Scalar::from(12345u64)));
+        let max = ((1u64 << 53) - 1) as f64;
+        assert_eq!(
+            Scalar::try_from_f64(max),
+            Ok(Scalar::from((1u64 << 53) - 1))
+        );
+
+        assert_eq!(
+            Scalar::try_from_f64(0.5),
+            Err(ConversionError::NotAnInteger)
+        );
+        assert_eq!(
+            Scalar::try_from_f64(3.25),
+            Err(ConversionError::NotAnInteger)
+        );
+        assert_eq!(Scalar::try_from_f64(-1.0), Err(ConversionError::Negative));
+        assert_eq!(Scalar::try_from_f64(-0.5), Err(ConversionError::Negative));
+        assert_eq!(
+            Scalar::try_from_f64(f64::NAN),
+            Err(ConversionError::NotFinite)
+        );
+        assert_eq!(
+            Scalar::try_from_f64(f64::INFINITY),
+            Err(ConversionError::NotFinite)
+        );
+        assert_eq!(
+            Scalar::try_from_f64(f64::NEG_INFINITY),
+            Err(ConversionError::NotFinite)
+        );
+        assert_eq!(
+            Scalar::try_from_f64((1u64 << 53) as f64),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            Scalar::try_from_f64(1e300),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_overflowing_add_bounded() {
+        let max = Scalar::from(u32::MAX as u64);
+        let (sum, overflow) = max.overflowing_add_bounded(&Scalar::ONE, 32);
+        assert_eq!(sum, Scalar::from(1u64 << 32));
+        assert!(overflow);
+
+        let (sum, overflow) = max.overflowing_add_bounded(&Scalar::ZERO, 32);
+        assert_eq!(sum, max);
+        assert!(!overflow);
+
+        let (sum, overflow) =
+            Scalar::from(1u64 << 31).overflowing_add_bounded(&Scalar::from((1u64 << 31) - 1), 32);
+        assert_eq!(sum, max);
+        assert!(!overflow);
+
+        assert!(
+            Scalar::from(1u64 << 31)
+                .overflowing_add_bounded(&Scalar::from(1u64 << 31), 32)
+                .1
+        );
+        assert!(!Scalar::ZERO.overflowing_add_bounded(&Scalar::ZERO, 0).1);
+        assert!(Scalar::ONE.overflowing_add_bounded(&Scalar::ZERO, 0).1);
+
+        // The integer sum is tracked even when the field sum wraps around
+        let (sum, overflow) = (-Scalar::ONE).overflowing_add_bounded(&Scalar::from(2u64), 255);
+        assert_eq!(sum, Scalar::ONE);
+        assert!(!overflow);
+        let (sum, overflow) = (-Scalar::ONE).overflowing_add_bounded(&-Scalar::ONE, 255);
+        assert_eq!(sum, -Scalar::from(2u64));
+        assert!(overflow);
+        assert!(!(-Scalar::ONE).overflowing_add_bounded(&-Scalar::ONE, 256).1);
+    }
+
+    #[test]
+    fn test_mul_add_sub() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut values = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        values.extend((0..5).map(|_| Scalar::random(&mut rng)));
+
+        for a in &values {
+            for b in &values {
+                for c in &values {
+                    assert_eq!(a.mul_add(b, c), a * b + c);
+                    assert_eq!(a.mul_sub(b, c), a * b - c);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cube_pow_small() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        scalars.extend((0..20).map(|_| Scalar::random(&mut rng)));
+        for a in scalars {
+            assert_eq!(a.cube(), a * a * a);
+            let mut b = a;
+            b.cube_assign();
+            assert_eq!(b, a * a * a);
+
+            assert_eq!(a.pow_small(0), Scalar::ONE);
+            assert_eq!(a.pow_small(1), a);
+            assert_eq!(a.pow_small(3), a.cube());
+            assert_eq!(a.pow_small(5), a * a * a * a * a);
+            for exp in [2u8, 7, 128, 255] {
+                assert_eq!(a.pow_small(exp), a.pow_vartime([exp as u64]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_raw_reduce() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::from_raw_reduce(MODULUS), Scalar::ZERO);
+        assert_eq!(Scalar::from_raw_reduce([1, 0, 0, 0]), Scalar::ONE);
+        assert_eq!(
+            Scalar::from_raw_reduce([u64::MAX; 4]),
+            Scalar::from_bytes_wide(&{
+                let mut wide = [0u8; 64];
+                wide[..32].copy_from_slice(&[0xff; 32]);
+                wide
+            })
+        );
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let limbs = a.to_raw();
+            assert_eq!(
+                Scalar::from_raw_reduce(limbs),
+                Scalar::from_raw(limbs).unwrap()
+            );
+
+            let mut limbs = [0u64; 4];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            let reduced = Scalar::from_raw_reduce(limbs);
+            #[cfg(target_pointer_width = "64")]
+            assert_eq!(reduced, Scalar::from(U256::from_words(limbs)));
+            let mut wide = [0u8; 64];
+            for (i, limb) in limbs.iter().enumerate() {
+                wide[8 * i..8 * (i + 1)].copy_from_slice(&limb.to_le_bytes());
+            }
+            assert_eq!(reduced, Scalar::from_bytes_wide(&wide));
+        }
+    }
+
+    #[test]
+    fn test_write_read_slice() {
+        use std::io::Cursor;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for n in [0, 1, 10] {
+            let scalars = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+            let mut buf = Vec::new();
+            Scalar::write_slice(&scalars, &mut buf).unwrap();
+            assert_eq!(buf.len(), 8 + n * Scalar::BYTES);
+            assert_eq!(buf[..8], (n as u64).to_le_bytes());
+            for (s, chunk) in scalars.iter().zip(buf[8..].chunks_exact(Scalar::BYTES)) {
+                assert_eq!(chunk, s.to_be_bytes());
+            }
+
+            let read = Scalar::read_slice(&mut Cursor::new(&buf)).unwrap();
+            assert_eq!(read, scalars);
+        }
+
+        let mut buf = Vec::new();
+        Scalar::write_slice(&[Scalar::ONE, Scalar::ONE], &mut buf).unwrap();
+        buf[8 + Scalar::BYTES..].copy_from_slice(&[0xff; Scalar::BYTES]);
+        let err = Scalar::read_slice(&mut Cursor::new(&buf)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        // Truncated input
+        buf.truncate(8 + Scalar::BYTES);
+        let err = Scalar::read_slice(&mut Cursor::new(&buf)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+
+            assert!(bool::from(a.div_exact(&Scalar::ZERO).is_none()));
+            assert_eq!(a.div_exact(&Scalar::ONE).unwrap(), a);
+            assert_eq!(a.div_exact(&b).unwrap(), a / b);
+            assert_eq!(a.div_exact(&b).unwrap() * b, a);
+        }
+        assert!(bool::from(Scalar::ZERO.div_exact(&Scalar::ZERO).is_none()));
+    }
+
+    #[test]
+    fn test_batch_from_okm() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut okm = [0u8; 96];
+        rng.fill_bytes(&mut okm);
+        let scalars = Scalar::batch_from_okm(&okm).unwrap();
+        assert_eq!(
+            scalars,
+            vec![
+                Scalar::from_okm(<&[u8; 48]>::try_from(&okm[..48]).unwrap()),
+                Scalar::from_okm(<&[u8; 48]>::try_from(&okm[48..]).unwrap()),
+            ]
+        );
+
+        assert_eq!(Scalar::batch_from_okm(&[]), Ok(vec![]));
+        assert_eq!(
+            Scalar::batch_from_okm(&okm[..50]),
+            Err(LengthError {
+                chunk_size: 48,
+                found: 50
+            })
+        );
+    }
+
+    #[test]
+    fn test_be_bytes_trimmed() {
+        assert!(Scalar::ZERO.to_be_bytes_trimmed().is_empty());
+        assert_eq!(Scalar::from_be_bytes_trimmed(&[]).unwrap(), Scalar::ZERO);
+
+        assert_eq!(Scalar::ONE.to_be_bytes_trimmed(), vec![1u8]);
+        assert_eq!(Scalar::from_be_bytes_trimmed(&[1]).unwrap(), Scalar::ONE);
+
+        let a = Scalar::from(0x0100u64);
+        assert_eq!(a.to_be_bytes_trimmed(), vec![1u8, 0]);
+        assert_eq!(Scalar::from_be_bytes_trimmed(&[1, 0]).unwrap(), a);
+
+        let a = -Scalar::ONE;
+        let bytes = a.to_be_bytes_trimmed();
+        assert_eq!(bytes.len(), Scalar::BYTES);
+        assert_eq!(bytes, a.to_be_bytes().to_vec());
+        assert_eq!(Scalar::from_be_bytes_trimmed(&bytes).unwrap(), a);
+
+        // Leading zeros are accepted
+        assert_eq!(
+            Scalar::from_be_bytes_trimmed(&[0, 0, 1]).unwrap(),
+            Scalar::ONE
+        );
+
+        let mut modulus = MODULUS_REPR;
+        modulus.reverse();
+        assert!(bool::from(
+            Scalar::from_be_bytes_trimmed(&modulus).is_none()
+        ));
+        assert!(bool::from(
+            Scalar::from_be_bytes_trimmed(&[0xff; 32]).is_none()
+        ));
+        assert!(bool::from(
+            Scalar::from_be_bytes_trimmed(&[0u8; 33]).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_decompose_glv() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let z = Scalar::from(BLS_X);
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE, z];
+        scalars.extend((0..100).map(|_| Scalar::random(&mut rng)));
+        for a in scalars {
+            let digits = a.decompose_glv();
+            assert!(digits.iter().all(|&d| d < BLS_X));
+            let recomposed = digits
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, &d| acc * z + Scalar::from(d));
+            assert_eq!(recomposed, a);
+        }
+        assert_eq!(z.decompose_glv(), [0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_div_rem_bls_x() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut inputs = vec![
+            (0, 0),
+            (0, BLS_X - 1),
+            (0, BLS_X),
+            (0, u64::MAX),
+            (BLS_X - 1, 0),
+            (BLS_X - 1, u64::MAX),
+        ];
+        inputs.extend((0..1000).map(|_| (rng.next_u64() % BLS_X, rng.next_u64())));
+        for (hi, lo) in inputs {
+            let n = ((hi as u128) << 64) | lo as u128;
+            let expected = ((n / BLS_X as u128) as u64, (n % BLS_X as u128) as u64);
+            assert_eq!(div_rem_bls_x(hi, lo), expected);
+        }
+    }
+
+    #[test]
+    fn test_to_wnaf() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        scalars.extend((0..20).map(|_| Scalar::random(&mut rng)));
+
+        for window in 2..=8 {
+            let bound = (1i16 << (window - 1)) - 1;
+            for a in scalars.iter() {
+                let wnaf = a.to_wnaf(window);
+
+                let mut recomposed = Scalar::ZERO;
+                let mut power = Scalar::ONE;
+                for (i, &digit) in wnaf.iter().enumerate() {
+                    if digit != 0 {
+                        assert_eq!(digit & 1, 1);
+                        assert!((digit as i16).abs() <= bound);
+                        assert!(wnaf[i + 1..].iter().take(window - 1).all(|&d| d == 0));
+                    }
+                    let d = Scalar::from((digit as i16).unsigned_abs() as u64);
+                    if digit < 0 {
+                        recomposed -= d * power;
+                    } else {
+                        recomposed += d * power;
+                    }
+                    power = power.double();
+                }
+                assert_eq!(recomposed, *a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_uniform_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..100 {
+            let mut wide = [0u8; 64];
+            rng.fill_bytes(&mut wide);
+            assert_eq!(
+                <Scalar as FromUniformBytes<64>>::from_uniform_bytes(&wide),
+                Scalar::from_bytes_wide(&wide)
+            );
+
+            let mut okm = [0u8; 48];
+            rng.fill_bytes(&mut okm);
+            assert_eq!(
+                <Scalar as FromUniformBytes<48>>::from_uniform_bytes(&okm),
+                Scalar::from_okm(&okm)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_be_hex_checked() {
+        let hex = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000";
+        assert_eq!(Scalar::from_be_hex_checked(hex), Ok(-Scalar::ONE));
+        assert_eq!(
+            Scalar::from_be_hex_checked(&format!("0x{}", hex)),
+            Ok(-Scalar::ONE)
+        );
+        assert_eq!(
+            Scalar::from_be_hex_checked(&hex.to_uppercase()),
+            Ok(-Scalar::ONE)
+        );
+
+        assert_eq!(
+            Scalar::from_be_hex_checked(&hex[1..]),
+            Err(HexError::InvalidLength {
+                expected: 64,
+                found: 63
+            })
+        );
+        assert_eq!(
+            Scalar::from_be_hex_checked(&format!("{}00", hex)),
+            Err(HexError::InvalidLength {
+                expected: 64,
+                found: 66
+            })
+        );
+        assert_eq!(
+            Scalar::from_be_hex_checked(&format!("{}g", &hex[1..])),
+            Err(HexError::InvalidCharacter { index: 63 })
+        );
+        assert_eq!(
+            Scalar::from_be_hex_checked(&format!("0x{}g", &hex[1..])),
+            Err(HexError::InvalidCharacter { index: 65 })
+        );
+        assert_eq!(
+            Scalar::from_be_hex_checked(
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+            ),
+            Err(HexError::NotInField)
+        );
+    }
+
+    #[test]
+    fn test_mul_u64() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        scalars.extend((0..20).map(|_| Scalar::random(&mut rng)));
+        for a in scalars {
+            for c in (0..=17).chain([1 << 32, 1 << 63, u64::MAX]) {
+                let expected = a * Scalar::from(c);
+                assert_eq!(a.mul_u64(c), expected);
+                assert_eq!(a * c, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conditional_lookup() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let table = (0..16)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        for (i, value) in table.iter().enumerate() {
+            assert_eq!(Scalar::conditional_lookup(&table, i), *value);
+        }
+        assert_eq!(
+            Scalar::conditional_lookup(&table, table.len()),
+            Scalar::ZERO
+        );
+        assert_eq!(Scalar::conditional_lookup(&[], 0), Scalar::ZERO);
+
+        // Repeated entries don't change the selected value
+        let repeated = [Scalar::ONE; 8];
+        for i in 0..repeated.len() {
+            assert_eq!(Scalar::conditional_lookup(&repeated, i), Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn test_multiply_accumulate() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let start = Scalar::random(&mut rng);
+        let mut acc = start;
+        Scalar::multiply_accumulate(&mut acc, &[], &[]);
+        assert_eq!(acc, start);
+
+        for n in 1..10 {
+            let coeffs = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+            let values = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+
+            let mut expected = start;
+            for i in 0..n {
+                expected += coeffs[i] * values[i];
+            }
+
+            let mut acc = start;
+            Scalar::multiply_accumulate(&mut acc, &coeffs, &values);
+            assert_eq!(acc, expected);
+        }
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert!(Scalar::hadamard(&[], &[]).is_empty());
+
+        for n in 1..10 {
+            let a = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+            let b = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+
+            let expected = a
+                .iter()
+                .zip(b.iter())
+                .map(|(a, b)| a * b)
+                .collect::<Vec<_>>();
+            assert_eq!(Scalar::hadamard(&a, &b), expected);
+
+            let mut c = a.clone();
+            Scalar::hadamard_assign(&mut c, &b);
+            assert_eq!(c, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hadamard_length_mismatch() {
+        Scalar::hadamard(&[Scalar::ONE; 2], &[Scalar::ONE; 3]);
+    }
+
+    #[test]
+    fn test_conditional_select_slice() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let a = (0..8).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let b = (0..8).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let mut dst = vec![Scalar::ZERO; 8];
+
+        Scalar::conditional_select_slice(&mut dst, &a, &b, Choice::from(0));
+        assert_eq!(dst, a);
+        Scalar::conditional_select_slice(&mut dst, &a, &b, Choice::from(1));
+        assert_eq!(dst, b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conditional_select_slice_length_mismatch() {
+        let mut dst = [Scalar::ZERO; 2];
+        Scalar::conditional_select_slice(
+            &mut dst,
+            &[Scalar::ONE; 2],
+            &[Scalar::ONE; 3],
+            Choice::from(0),
+        );
+    }
+
+    #[test]
+    fn test_low_high_bits() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let max = Scalar::from(u64::MAX);
+        assert_eq!(max.low_bits(64), max);
+        assert_eq!(max.high_bits(64), Scalar::ZERO);
+        assert_eq!(max.low_bits(8), Scalar::from(0xffu64));
+        assert_eq!(max.high_bits(8), Scalar::from(u64::MAX >> 8));
+
+        for _ in 0..10 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(s.low_bits(0), Scalar::ZERO);
+            assert_eq!(s.high_bits(0), s);
+            assert_eq!(s.low_bits(256), s);
+            assert_eq!(s.high_bits(256), Scalar::ZERO);
+            for k in [1, 7, 63, 64, 65, 128, 130, 200, 254, 255] {
+                let low = s.low_bits(k);
+                let high = s.high_bits(k);
+                assert_eq!(low + high.shl(k as usize), s);
+                assert!(low.bits_le()[k as usize..].iter().all(|b| !b));
+                assert_eq!(
+                    high.bits_le()[..256 - k as usize],
+                    s.bits_le()[k as usize..]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_vector_mul() {
+        let s = |x: u64| Scalar::from(x);
+        let matrix = vec![
+            vec![s(2), s(3), s(1)],
+            vec![s(1), s(1), s(1)],
+            vec![s(4), s(0), -s(5)],
+        ];
+        let vector = [s(7), s(11), s(13)];
+        // 2*7 + 3*11 + 13, 7 + 11 + 13, 4*7 - 5*13
+        let expected = vec![s(60), s(31), -s(37)];
+        assert_eq!(Scalar::matrix_vector_mul(&matrix, &vector), expected);
+
+        assert_eq!(Scalar::sum_of_products(&matrix[0], &vector), s(60));
+        assert_eq!(Scalar::sum_of_products(&[], &[]), Scalar::ZERO);
+        assert!(Scalar::matrix_vector_mul(&[], &vector).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_vector_mul_dimension_mismatch() {
+        let matrix = vec![vec![Scalar::ONE; 3], vec![Scalar::ONE; 2]];
+        Scalar::matrix_vector_mul(&matrix, &[Scalar::ONE; 3]);
+    }
+
+    #[test]
+    fn test_divide_by_vanishing() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let log_n = 3;
+        let n = 1usize << log_n;
+        let omega = Scalar::root_of_unity(log_n).unwrap();
+        let g = Scalar::MULTIPLICATIVE_GENERATOR;
+        let eval = |coeffs: &[Scalar], x: Scalar| {
+            coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+        };
+
+        // h(x) * (x^n - 1) vanishes on the subgroup
+        let h = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let mut z = vec![Scalar::ZERO; n + 1];
+        z[0] = -Scalar::ONE;
+        z[n] = Scalar::ONE;
+        let mut p = vec![Scalar::ZERO; 2 * n];
+        for (i, hi) in h.iter().enumerate() {
+            for (j, zj) in z.iter().enumerate() {
+                p[i + j] += hi * zj;
+            }
+        }
+        let mut w = Scalar::ONE;
+        for _ in 0..n {
+            assert_eq!(eval(&p, w), Scalar::ZERO);
+            w *= omega;
+        }
+
+        // Evaluate on the coset, divide, and interpolate back
+        let points = (0..n)
+            .map(|i| g * omega.pow_vartime([i as u64]))
+            .collect::<Vec<_>>();
+        let mut evals = points.iter().map(|x| eval(&p, *x)).collect::<Vec<_>>();
+        assert!(bool::from(Scalar::divide_by_vanishing(&mut evals, &g)));
+        let n_inv = Scalar::from(n as u64).invert().unwrap();
+        let recovered = (0..n)
+            .map(|i| {
+                points
+                    .iter()
+                    .zip(evals.iter())
+                    .map(|(x, y)| y * x.invert().unwrap().pow_vartime([i as u64]))
+                    .sum::<Scalar>()
+                    * n_inv
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(recovered, h);
+
+        // Z_H is zero on the subgroup itself
+        let mut evals = h.clone();
+        assert!(!bool::from(Scalar::divide_by_vanishing(&mut evals, &omega)));
+        assert_eq!(evals, h);
+    }
+
+    #[test]
+    fn test_invert_and_mul() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let a = Scalar::random_vec(&mut rng, 8);
+        let mut b = Scalar::random_nonzero_vec(&mut rng, 8);
+        let quotients = Scalar::invert_and_mul(&a, &b).unwrap();
+        assert_eq!(quotients.len(), 8);
+        for ((q, a), b) in quotients.iter().zip(a.iter()).zip(b.iter()) {
+            assert_eq!(*q, a * b.invert().unwrap());
+            assert_eq!(q * b, *a);
+        }
+
+        assert!(Scalar::invert_and_mul(&[], &[]).unwrap().is_empty());
+
+        b[3] = Scalar::ZERO;
+        assert!(bool::from(Scalar::invert_and_mul(&a, &b).is_none()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invert_and_mul_length_mismatch() {
+        let _ = Scalar::invert_and_mul(&[Scalar::ONE; 2], &[Scalar::ONE; 3]);
+    }
+
+    #[test]
+    fn test_axpy() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let a = Scalar::random(&mut rng);
+        let x = Scalar::random_vec(&mut rng, 8);
+        let y = Scalar::random_vec(&mut rng, 8);
+
+        let mut expected = y.clone();
+        for i in 0..8 {
+            expected[i] = a * x[i] + y[i];
+        }
+        let mut actual = y.clone();
+        Scalar::axpy(&a, &x, &mut actual);
+        assert_eq!(actual, expected);
+
+        let mut actual = y.clone();
+        Scalar::axpy(&Scalar::ZERO, &x, &mut actual);
+        assert_eq!(actual, y);
+
+        Scalar::axpy(&a, &[], &mut []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axpy_length_mismatch() {
+        Scalar::axpy(&Scalar::ONE, &[Scalar::ONE; 2], &mut [Scalar::ONE; 3]);
+    }
+
+    #[test]
+    fn test_vector_add_sub() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let a = (0..8).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let b = (0..8).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+
+        let mut sum = a.clone();
+        Scalar::add_assign_slice(&mut sum, &b);
+        let mut diff = a.clone();
+        Scalar::sub_assign_slice(&mut diff, &b);
+        for i in 0..8 {
+            assert_eq!(sum[i], a[i] + b[i]);
+            assert_eq!(diff[i], a[i] - b[i]);
+        }
+        assert_eq!(Scalar::add_vectors(&a, &b), sum);
+        assert_eq!(Scalar::sub_vectors(&a, &b), diff);
+
+        // Polynomial semantics for different lengths
+        let short = &b[..3];
+        let sum = Scalar::add_vectors(&a, short);
+        assert_eq!(sum, Scalar::add_vectors(short, &a));
+        let diff = Scalar::sub_vectors(&a, short);
+        let neg_diff = Scalar::sub_vectors(short, &a);
+        assert_eq!(sum.len(), 8);
+        assert_eq!(diff.len(), 8);
+        assert_eq!(neg_diff.len(), 8);
+        for i in 0..8 {
+            let s = if i < 3 { short[i] } else { Scalar::ZERO };
+            assert_eq!(sum[i], a[i] + s);
+            assert_eq!(diff[i], a[i] - s);
+            assert_eq!(neg_diff[i], -diff[i]);
+        }
+
+        assert!(Scalar::add_vectors(&[], &[]).is_empty());
+        assert_eq!(Scalar::sub_vectors(&[], &a[..2]), vec![-a[0], -a[1]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_slice_length_mismatch() {
+        Scalar::add_assign_slice(&mut [Scalar::ONE; 2], &[Scalar::ONE; 3]);
+    }
+
+    #[test]
+    fn test_scale_slice() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let values = (0..10)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut scaled = values.clone();
+        Scalar::scale_slice(&mut scaled, &Scalar::ONE);
+        assert_eq!(scaled, values);
+
+        let factor = Scalar::random(&mut rng);
+        Scalar::scale_slice(&mut scaled, &factor);
+        for (s, v) in scaled.iter().zip(values.iter()) {
+            assert_eq!(*s, v * factor);
+        }
+
+        let mut empty: [Scalar; 0] = [];
+        Scalar::scale_slice(&mut empty, &factor);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let a = Scalar::from(0x1234_5678u64);
+        let bytes = a.to_be_bytes();
+        assert_eq!(Scalar::try_from(&bytes[..]), Ok(a));
+        assert_eq!(Scalar::try_from(bytes.to_vec()), Ok(a));
+
+        assert_eq!(
+            Scalar::try_from(&bytes[1..]),
+            Err(ScalarError::WrongLength {
+                expected: 32,
+                found: 31
+            })
+        );
+        assert_eq!(
+            Scalar::try_from(&[0u8; 33][..]),
+            Err(ScalarError::WrongLength {
+                expected: 32,
+                found: 33
+            })
+        );
+
+        let mut modulus = MODULUS_REPR;
+        modulus.reverse();
+        assert_eq!(
+            Scalar::try_from(&modulus[..]),
+            Err(ScalarError::NotCanonical)
+        );
+        assert_eq!(
+            Scalar::try_from(&[0xffu8; 32][..]),
+            Err(ScalarError::NotCanonical)
+        );
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let a = Scalar::from(0x1234_5678u64);
+        assert_eq!(Scalar::try_from_le_bytes(&a.to_le_bytes()), Ok(a));
+        assert_eq!(Scalar::try_from_be_bytes(&a.to_be_bytes()), Ok(a));
+        assert_eq!(
+            Scalar::try_from_le_bytes(&(-Scalar::ONE).to_le_bytes()),
+            Ok(-Scalar::ONE)
+        );
+
+        let mut modulus = MODULUS_REPR;
+        assert_eq!(
+            Scalar::try_from_le_bytes(&modulus),
+            Err(ScalarError::NotCanonical)
+        );
+        modulus.reverse();
+        assert_eq!(
+            Scalar::try_from_be_bytes(&modulus),
+            Err(ScalarError::NotCanonical)
+        );
+        assert_eq!(
+            Scalar::try_from_le_bytes(&[0xff; 32]),
+            Err(ScalarError::NotCanonical)
+        );
+
+        assert_eq!(
+            Scalar::try_from_le_bytes(&[0u8; 31]),
+            Err(ScalarError::WrongLength {
+                expected: 32,
+                found: 31
+            })
+        );
+        assert_eq!(
+            Scalar::try_from_be_bytes(&[]),
+            Err(ScalarError::WrongLength {
+                expected: 32,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_reduce_bytes_be() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Scalar::reduce_bytes_be(&[]), Scalar::ZERO);
+        assert_eq!(Scalar::reduce_bytes_be(&[7]), Scalar::from(7u64));
+        assert_eq!(Scalar::reduce_bytes_be(&[1, 0]), Scalar::from(256u64));
+
+        let mut modulus = MODULUS_REPR;
+        modulus.reverse();
+        assert_eq!(Scalar::reduce_bytes_be(&modulus), Scalar::ZERO);
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let be = a.to_be_bytes();
+            assert_eq!(Scalar::reduce_bytes_be(&be), a);
+            assert_eq!(Scalar::reduce_bytes_be(&be[8..]), {
+                let mut buf = [0u8; 32];
+                buf[8..].copy_from_slice(&be[8..]);
+                Scalar::from_be_bytes(&buf).unwrap()
+            });
+
+            let mut wide = [0u8; 64];
+            rng.fill_bytes(&mut wide);
+            let mut wide_le = wide;
+            wide_le.reverse();
+            assert_eq!(
+                Scalar::reduce_bytes_be(&wide),
+                Scalar::from_bytes_wide(&wide_le)
+            );
+        }
+
+        // (2^1024 - 1) mod q
+        assert_eq!(
+            Scalar::reduce_bytes_be(&[0xff; 128]),
+            Scalar::from_raw([
+                0x1b9c_9191_d083_1ad2,
+                0x08fd_47d3_6f83_0a6c,
+                0xf948_ab00_b364_c750,
+                0x08a6_3526_916f_202c,
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_root_of_unity_log_n() {
+        assert_eq!(Scalar::root_of_unity(S), Some(ROOT_OF_UNITY));
+        assert_eq!(Scalar::root_of_unity(0), Some(Scalar::ONE));
+        assert_eq!(Scalar::root_of_unity(1), Some(-Scalar::ONE));
+        assert_eq!(Scalar::root_of_unity(S + 1), None);
+        assert_eq!(Scalar::root_of_unity_inv(S + 1), None);
+
+        for log_n in [1, 2, 5, 10, 16, 31, 32] {
+            let root = Scalar::root_of_unity(log_n).unwrap();
+            assert_eq!(root.pow_vartime([1u64 << log_n]), Scalar::ONE);
+            assert_eq!(root.pow_vartime([1u64 << (log_n - 1)]), -Scalar::ONE);
+
+            let root_inv = Scalar::root_of_unity_inv(log_n).unwrap();
+            assert_eq!(root * root_inv, Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn test_write_bytes() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+        let mut buf = [0u8; 32];
+        for s in &scalars {
+            s.write_le(&mut buf);
+            assert_eq!(buf, s.to_le_bytes());
+            s.write_be(&mut buf);
+            assert_eq!(buf, s.to_be_bytes());
+        }
+
+        let mut out = Vec::new();
+        Scalar::write_all_le(&scalars, &mut out).unwrap();
+        let expected: Vec<u8> = scalars.iter().flat_map(|s| s.to_le_bytes()).collect();
+        assert_eq!(out, expected);
+
+        let mut out = Vec::new();
+        Scalar::write_all_le(&[], &mut out).unwrap();
+        assert!(out.is_empty());
+    }
+
     #[test]
     fn test_from_u512_zero() {
         assert_eq!(
@@ -9,10 +9,8 @@ use ark_serialize::{
 };
 use blst::blst_uint64_from_fr;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-use core::str::FromStr;
 use ff::{Field, PrimeField};
 use num_bigint::BigUint;
-use num_traits::Num;
 use std::iter;
 use subtle::ConstantTimeEq;
 
@@ -191,7 +189,7 @@ impl UniformRand for Scalar {
 impl FftField for Scalar {
     const GENERATOR: Self = <Self as PrimeField>::MULTIPLICATIVE_GENERATOR;
     const TWO_ADICITY: u32 = <Self as PrimeField>::S;
-    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::from_raw_unchecked([
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::from_montgomery_limbs_unchecked([
         0xb9b58d8c5f0e466au64,
         0x5b1b4c801819d7ecu64,
         0x0af53ae352a31e64u64,
@@ -199,20 +197,6 @@ impl FftField for Scalar {
     ]);
 }
 
-impl FromStr for Scalar {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Ark expects base 10
-        let num = BigUint::from_str_radix(s, 10).map_err(|_| ())?;
-        let modulus = BigUint::from_bytes_be(&<Self as ArkPrimeField>::MODULUS.to_bytes_be());
-        if num > modulus {
-            return Err(());
-        }
-        Ok(Self::from(num))
-    }
-}
-
 impl From<<Scalar as ArkPrimeField>::BigInt> for Scalar {
     fn from(repr: <Scalar as ArkPrimeField>::BigInt) -> Self {
         Self::from_bigint(repr).expect("Failed to convert from BigInt")
@@ -236,12 +220,6 @@ impl From<BigUint> for Scalar {
     }
 }
 
-impl From<Scalar> for BigUint {
-    fn from(scalar: Scalar) -> Self {
-        BigUint::from_bytes_be(&scalar.to_be_bytes())
-    }
-}
-
 impl ArkPrimeField for Scalar {
     type BigInt = BigInteger256;
     const MODULUS: Self::BigInt = BigInteger256::new([
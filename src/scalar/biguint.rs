@@ -0,0 +1,24 @@
+use num_bigint::BigUint;
+
+use crate::{Scalar, ScalarError};
+
+impl From<Scalar> for BigUint {
+    fn from(scalar: Scalar) -> Self {
+        BigUint::from_bytes_be(&scalar.to_be_bytes())
+    }
+}
+
+impl TryFrom<&BigUint> for Scalar {
+    type Error = ScalarError;
+
+    /// Converts `value` into a `Scalar`, failing if it is not less than the modulus.
+    fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+        let bytes = value.to_bytes_be();
+        if bytes.len() > Scalar::BYTES {
+            return Err(ScalarError::NotCanonical);
+        }
+        let mut be_bytes = [0u8; Scalar::BYTES];
+        be_bytes[Scalar::BYTES - bytes.len()..].copy_from_slice(&bytes);
+        Option::from(Scalar::from_be_bytes(&be_bytes)).ok_or(ScalarError::NotCanonical)
+    }
+}
@@ -10,7 +10,7 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::gt::GtRepr;
+use crate::gt::{GtCompressed, GtRepr};
 use crate::util::decode_hex_into_slice;
 use crate::{
     fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6, util, G1Affine, G1Projective, G2Affine, G2Projective,
@@ -256,6 +256,80 @@ impl<'de> Deserialize<'de> for Gt {
     }
 }
 
+impl Serialize for GtCompressed {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            format!("{:x}", self).serialize(s)
+        } else {
+            let bytes = self.to_bytes_le();
+            let mut tupler = s.serialize_tuple(bytes.len())?;
+            for byte in bytes.iter() {
+                tupler.serialize_element(byte)?;
+            }
+            tupler.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GtCompressed {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn validate<E: DeserializeError>(
+            bytes: &[u8; GtCompressed::BYTES],
+        ) -> Result<GtCompressed, E> {
+            GtCompressed::from_bytes_le(bytes)
+                .filter(|c| c.uncompress().is_some())
+                .ok_or_else(|| E::custom("invalid compressed value"))
+        }
+
+        if d.is_human_readable() {
+            let hex_str = <String>::deserialize(d)?;
+            let mut bytes = [0u8; GtCompressed::BYTES];
+            if hex_str.len() != 2 * bytes.len() {
+                return Err(DeserializeError::custom(format!(
+                    "invalid length, expected {} hex characters, received {}",
+                    2 * bytes.len(),
+                    hex_str.len()
+                )));
+            }
+            util::try_decode_hex_into_slice(&mut bytes, hex_str.as_bytes()).map_err(|index| {
+                DeserializeError::custom(format!("invalid hex character at index {}", index))
+            })?;
+            validate(&bytes)
+        } else {
+            struct ArrayVisitor;
+
+            impl<'de> Visitor<'de> for ArrayVisitor {
+                type Value = GtCompressed;
+
+                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                    write!(f, "an array of {} bytes", GtCompressed::BYTES)
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut bytes = [0u8; GtCompressed::BYTES];
+                    for (i, byte) in bytes.iter_mut().enumerate() {
+                        *byte = seq
+                            .next_element()?
+                            .ok_or_else(|| DeserializeError::invalid_length(i, &self))?;
+                    }
+                    validate(&bytes)
+                }
+            }
+
+            d.deserialize_tuple(GtCompressed::BYTES, ArrayVisitor)
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct Fp2Ser {
     c0: Fp,
@@ -569,4 +643,47 @@ mod tests {
         assert!(gt2.is_ok());
         assert_eq!(gt, gt2.unwrap());
     }
+
+    #[test]
+    fn serde_gt_compressed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..5 {
+            let p = G1Projective::random(&mut rng).to_affine();
+            let q = G2Projective::random(&mut rng).to_affine();
+            let compressed = pairing(&p, &q).compress().unwrap();
+            test_roundtrip(&compressed);
+        }
+
+        let compressed = pairing(&G1Affine::generator(), &G2Affine::generator())
+            .compress()
+            .unwrap();
+        let json = serde_json::to_string(&compressed).unwrap();
+        assert_eq!(json.len(), 2 * GtCompressed::BYTES + 2);
+        let mut bare = serde_bare::to_vec(&compressed).unwrap();
+        assert_eq!(bare.len(), GtCompressed::BYTES);
+
+        // Not canonical `Fp` coefficients
+        let invalid = serde_bare::to_vec(&[0xffu8; 32]).unwrap().repeat(9);
+        assert!(serde_bare::from_slice::<GtCompressed>(&invalid).is_err());
+
+        // Canonical coefficients, but not the compression of a subgroup element
+        bare[0] ^= 1;
+        assert!(serde_bare::from_slice::<GtCompressed>(&bare).is_err());
+
+        let mut json = json.into_bytes();
+        json[1] = if json[1] == b'0' { b'1' } else { b'0' };
+        assert!(serde_json::from_slice::<GtCompressed>(&json).is_err());
+        assert!(serde_json::from_str::<GtCompressed>("\"00\"").is_err());
+
+        let non_hex = format!("\"{}\"", "zz".repeat(GtCompressed::BYTES));
+        assert!(serde_json::from_str::<GtCompressed>(&non_hex).is_err());
+
+        let valid = serde_json::to_string(&compressed).unwrap();
+        let odd_length = format!("{}0\"", &valid[..valid.len() - 1]);
+        assert!(serde_json::from_str::<GtCompressed>(&odd_length).is_err());
+    }
 }
@@ -0,0 +1,34 @@
+//! Randomized conformance tests, for checking other implementations of the
+//! `ff`, `group` and `pairing` traits the same way this crate checks its own.
+//!
+//! Every function panics if a check fails.
+//!
+//! ```
+//! use blstrs_plus::{test_utils, Scalar};
+//!
+//! test_utils::run_field_tests::<Scalar>();
+//! ```
+
+use ff::Field;
+use group::prime::PrimeCurve;
+use pairing_lib::{Engine, MultiMillerLoop};
+
+/// Checks the field laws for random elements of `F`.
+pub fn run_field_tests<F: Field>() {
+    crate::tests::field::random_field_tests::<F>();
+}
+
+/// Checks that `sqrt` inverts `square` for random elements of `F`.
+pub fn run_sqrt_tests<F: Field>() {
+    crate::tests::field::random_sqrt_tests::<F>();
+}
+
+/// Checks the group laws and encodings of `G`.
+pub fn run_curve_tests<G: PrimeCurve>() {
+    group::tests::curve_tests::<G>();
+}
+
+/// Checks the bilinearity and Miller loop consistency of the pairing of `E`.
+pub fn run_engine_tests<E: Engine + MultiMillerLoop>() {
+    crate::tests::engine::engine_tests::<E>();
+}
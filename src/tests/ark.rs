@@ -96,3 +96,33 @@ fn commit_in_each_omega_i(
         .map(|omega_pow_i| open(srs, poly, omega_pow_i).1)
         .collect()
 }
+
+#[test]
+fn gt_fq12_roundtrip() {
+    use crate::{pairing, Gt};
+    use ark_bls12_381::{Bls12_381, Fq12};
+    use ark_ec::{pairing::Pairing, AffineRepr};
+    use group::{Curve, Group};
+
+    let g = Gt::generator();
+    let fq12 = Fq12::from(g);
+    let expected = Bls12_381::pairing(
+        ark_bls12_381::G1Affine::generator(),
+        ark_bls12_381::G2Affine::generator(),
+    );
+    assert_eq!(fq12, expected.0);
+    assert_eq!(Gt::try_from(fq12).unwrap(), g);
+
+    for _ in 0..5 {
+        let p = G1Projective::random(&mut rand::rngs::OsRng).to_affine();
+        let q = G2Projective::random(&mut rand::rngs::OsRng).to_affine();
+        let gt = pairing(&p, &q);
+        assert_eq!(Gt::try_from(Fq12::from(gt)).unwrap(), gt);
+    }
+
+    assert_eq!(
+        Gt::try_from(Fq12::from(Gt::identity())).unwrap(),
+        Gt::identity()
+    );
+    assert!(Gt::try_from(Fq12::from(2u64)).is_err());
+}
@@ -1,4 +1,4 @@
-use ff::{Field, PrimeField};
+use ff::Field;
 use rand_core::{RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
@@ -70,7 +70,8 @@ pub fn random_field_tests<F: Field>() {
     }
 }
 
-pub fn from_str_tests<F: PrimeField>() {
+#[cfg(test)]
+pub fn from_str_tests<F: ff::PrimeField>() {
     {
         let a = "84395729384759238745923745892374598234705297301958723458712394587103249587213984572934750213947582345792304758273458972349582734958273495872304598234";
         let b = "38495729084572938457298347502349857029384609283450692834058293405982304598230458230495820394850293845098234059823049582309485203948502938452093482039";
@@ -1,4 +1,4 @@
-#[cfg(feature = "ark")]
+#[cfg(all(test, feature = "ark"))]
 pub mod ark;
 pub mod engine;
 pub mod field;
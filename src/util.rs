@@ -7,6 +7,17 @@ pub fn decode_hex_into_slice(buffer: &mut [u8], bytes: &[u8]) {
     }
 }
 
+/// Decode `bytes` as hexadecimal into `buffer`, returning the index of the first
+/// character that is not a hexadecimal digit on failure.
+pub fn try_decode_hex_into_slice(buffer: &mut [u8], bytes: &[u8]) -> Result<(), usize> {
+    debug_assert_eq!(buffer.len(), bytes.len() / 2);
+    if let Some(index) = bytes.iter().position(|b| !b.is_ascii_hexdigit()) {
+        return Err(index);
+    }
+    decode_hex_into_slice(buffer, bytes);
+    Ok(())
+}
+
 /// Decode a single byte encoded as two hexadecimal characters.
 pub fn decode_hex_byte(bytes: [u8; 2]) -> u8 {
     let mut i = 0;